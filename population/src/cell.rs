@@ -0,0 +1,86 @@
+use crate::disease::Health;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Animal {
+    pub energy: u32,
+    pub health: Health
+}
+
+impl Animal {
+    pub fn new(energy: u32) -> Animal {
+        Animal {
+            energy,
+            health: Health::Susceptible
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Fox(Animal),
+    Rabbit(Animal),
+    Grass
+}
+
+impl Cell {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Cell::Empty     => " ",
+            Cell::Fox(_)    => "R",
+            Cell::Rabbit(_) => "N",
+            Cell::Grass     => "F"
+        }
+    }
+
+    pub fn animal(self) -> Option<Animal> {
+        match self {
+            Cell::Fox(animal) | Cell::Rabbit(animal) => Some(animal),
+            _                                        => None
+        }
+    }
+
+    pub fn with_energy(self, energy: u32) -> Cell {
+        match self {
+            Cell::Fox(animal)    => Cell::Fox(Animal { energy, ..animal }),
+            Cell::Rabbit(animal) => Cell::Rabbit(Animal { energy, ..animal }),
+            _                    => self
+        }
+    }
+
+    pub fn with_health(self, health: Health) -> Cell {
+        match self {
+            Cell::Fox(animal)    => Cell::Fox(Animal { health, ..animal }),
+            Cell::Rabbit(animal) => Cell::Rabbit(Animal { health, ..animal }),
+            _                    => self
+        }
+    }
+
+    pub fn is_fox(self) -> bool {
+        matches!(self, Cell::Fox(_))
+    }
+
+    pub fn is_rabbit(self) -> bool {
+        matches!(self, Cell::Rabbit(_))
+    }
+
+    pub fn is_empty(self) -> bool {
+        self == Cell::Empty
+    }
+
+    pub fn is_free(self) -> bool {
+        matches!(self, Cell::Empty | Cell::Grass)
+    }
+
+    pub fn is_animal(self) -> bool {
+        self.is_fox() || self.is_rabbit()
+    }
+
+    pub fn is_infected(self) -> bool {
+        self.has_health(Health::is_infected)
+    }
+
+    pub fn has_health<P: Fn(Health) -> bool>(self, predicate: P) -> bool {
+        self.animal().is_some_and(|animal| predicate(animal.health))
+    }
+}
@@ -0,0 +1,57 @@
+use std::str::FromStr;
+
+pub const MAX_GRID_SIDE: usize = 2000;
+
+pub const USAGE: &str = "Hasznalat: population [--grid <szelesseg>x<magassag>] [--seed <szam>] [--headless]
+
+Opciok:
+  -g, --grid <szelesseg>x<magassag>  a ret merete (1..2000 x 1..2000), alapertelmezes szerint
+                                     a terminal meretehez igazodik
+  -s, --seed <szam>                  a szimulacio seed-je (0..18446744073709551615)
+  -x, --headless                     futtatas a TUI nelkul a leallasi feltetelek teljesuleseig;
+                                     a ret nagyobb is lehet a terminalnal, az osszegzes
+                                     a kimenetre es fajlba kerul
+  -h, --help                         ez a sugo";
+
+#[derive(Default)]
+pub struct CommandLineOptions {
+    pub grid_size: Option<(usize, usize)>,
+    pub seed: Option<u64>,
+    pub headless: bool,
+    pub show_help: bool
+}
+
+pub fn parse_arguments<I: Iterator<Item = String>>(mut arguments: I) -> Result<CommandLineOptions, String> {
+    let mut options = CommandLineOptions::default();
+
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "-g" | "--grid"     => options.grid_size = Some(parse_grid_size(&parse_value::<String>(&argument, arguments.next())?)?),
+            "-s" | "--seed"     => options.seed = Some(parse_value(&argument, arguments.next())?),
+            "-x" | "--headless" => options.headless = true,
+            "-h" | "--help"     => options.show_help = true,
+            _                   => return Err(format!("Ismeretlen argumentum: {}", argument))
+        }
+    }
+
+    Ok(options)
+}
+
+pub fn parse_grid_size(input: &str) -> Result<(usize, usize), String> {
+    let error           = || format!("Ervenytelen retmeret: \"{}\" (<szelesseg>x<magassag>, 1..{} x 1..{})", input, MAX_GRID_SIDE, MAX_GRID_SIDE);
+    let (width, height) = input.trim()
+                               .split_once(['x', 'X'])
+                               .ok_or_else(error)?;
+
+    match (width.parse::<usize>(), height.parse::<usize>()) {
+        (Ok(width), Ok(height)) if (1..=MAX_GRID_SIDE).contains(&width) && (1..=MAX_GRID_SIDE).contains(&height) => Ok((width, height)),
+        _ => Err(error())
+    }
+}
+
+fn parse_value<T: FromStr>(argument: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("Hianyzo ertek: {}", argument))?;
+
+    value.parse::<T>()
+         .map_err(|_| format!("Ervenytelen ertek ({}): {}", argument, value))
+}
@@ -0,0 +1,63 @@
+use crate::cell::Cell;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Health {
+    Susceptible,
+    Infected { remaining: u32 },
+    Recovered
+}
+
+impl Health {
+    pub fn is_susceptible(self) -> bool {
+        matches!(self, Health::Susceptible)
+    }
+
+    pub fn is_infected(self) -> bool {
+        matches!(self, Health::Infected { .. })
+    }
+
+    pub fn is_recovered(self) -> bool {
+        matches!(self, Health::Recovered)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DiseaseParameters {
+    pub initial_infection_probability: f64,
+    pub transmission_probability: f64,
+    pub recovery_time: u32,
+    pub mortality_probability: f64,
+    pub fox_susceptibility: f64,
+    pub rabbit_susceptibility: f64
+}
+
+impl Default for DiseaseParameters {
+    fn default() -> DiseaseParameters {
+        DiseaseParameters {
+            initial_infection_probability: 0.02,
+            transmission_probability: 0.5,
+            recovery_time: 5,
+            mortality_probability: 0.3,
+            fox_susceptibility: 0.5,
+            rabbit_susceptibility: 1.0
+        }
+    }
+}
+
+impl DiseaseParameters {
+    pub fn susceptibility(&self, cell: Cell) -> f64 {
+        match cell {
+            Cell::Fox(_)    => self.fox_susceptibility,
+            Cell::Rabbit(_) => self.rabbit_susceptibility,
+            _               => 0.0
+        }
+    }
+
+    pub fn transmission_chance(&self, cell: Cell) -> f64 {
+        self.transmission_probability * self.susceptibility(cell)
+    }
+
+    pub fn infection(&self) -> Health {
+        Health::Infected { remaining: self.recovery_time }
+    }
+}
@@ -0,0 +1,272 @@
+use crate::{ cell::Cell, disease::Health };
+
+const NEIGHBOUR_OFFSETS: [(isize, isize); 4] = [(0, -1), (-1, 0), (0, 1), (1, 0)];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PopulationCounts {
+    pub foxes: u64,
+    pub rabbits: u64,
+    pub grass: u64,
+    pub empty: u64,
+    pub susceptible: u64,
+    pub infected: u64,
+    pub recovered: u64
+}
+
+impl PopulationCounts {
+    pub fn animals(&self) -> u64 {
+        self.foxes + self.rabbits
+    }
+
+    fn tally(&mut self, cell: Cell, added: bool) {
+        let kind_counter = match cell {
+            Cell::Empty     => &mut self.empty,
+            Cell::Fox(_)    => &mut self.foxes,
+            Cell::Rabbit(_) => &mut self.rabbits,
+            Cell::Grass     => &mut self.grass
+        };
+        adjust(kind_counter, added);
+
+        let health_counter = match cell.animal().map(|animal| animal.health) {
+            Some(Health::Susceptible)     => &mut self.susceptible,
+            Some(Health::Infected { .. }) => &mut self.infected,
+            Some(Health::Recovered)       => &mut self.recovered,
+            None                          => return
+        };
+        adjust(health_counter, added);
+    }
+}
+
+fn adjust(counter: &mut u64, added: bool) {
+    if added {
+        *counter += 1;
+    } else {
+        *counter -= 1;
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CellGroup {
+    Foxes,
+    Rabbits,
+    Empty,
+    Infected
+}
+
+impl CellGroup {
+    pub const ALL: [CellGroup; 4] = [CellGroup::Foxes, CellGroup::Rabbits, CellGroup::Empty, CellGroup::Infected];
+
+    pub fn contains(self, cell: Cell) -> bool {
+        match self {
+            CellGroup::Foxes    => cell.is_fox(),
+            CellGroup::Rabbits  => cell.is_rabbit(),
+            CellGroup::Empty    => cell.is_empty(),
+            CellGroup::Infected => cell.is_infected()
+        }
+    }
+
+    fn slot(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Clone, Debug)]
+struct PositionIndex {
+    members: Vec<u32>,
+    slots: Vec<u32>
+}
+
+impl PositionIndex {
+    fn new(cell_count: usize, filled: bool) -> PositionIndex {
+        if filled {
+            PositionIndex { members: (0..cell_count as u32).collect(), slots: (0..cell_count as u32).collect() }
+        } else {
+            PositionIndex { members: Vec::new(), slots: vec![NO_SLOT; cell_count] }
+        }
+    }
+
+    fn insert(&mut self, index: usize) {
+        if self.slots[index] == NO_SLOT {
+            self.slots[index] = self.members.len() as u32;
+            self.members.push(index as u32);
+        }
+    }
+
+    fn remove(&mut self, index: usize) {
+        let slot = self.slots[index];
+        if slot != NO_SLOT {
+            self.slots[index] = NO_SLOT;
+            self.members.swap_remove(slot as usize);
+            if let Some(moved) = self.members.get(slot as usize) {
+                self.slots[*moved as usize] = slot;
+            }
+        }
+    }
+}
+
+const NO_SLOT: u32 = u32::MAX;
+
+#[derive(Clone, Debug)]
+pub struct Grid {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+    counts: PopulationCounts,
+    positions: [PositionIndex; 4]
+}
+
+impl PartialEq for Grid {
+    fn eq(&self, other: &Grid) -> bool {
+        (self.width, self.height) == (other.width, other.height) && self.cells == other.cells
+    }
+}
+
+impl Eq for Grid {}
+
+impl Grid {
+    pub fn new(width: usize, height: usize) -> Grid {
+        Grid {
+            width,
+            height,
+            cells: vec![Cell::Empty; width * height],
+            counts: PopulationCounts {
+                empty: (width * height) as u64,
+                ..PopulationCounts::default()
+            },
+            positions: CellGroup::ALL.map(|group| PositionIndex::new(width * height, group == CellGroup::Empty))
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, x_position: usize, y_position: usize) -> Cell {
+        self.cells[self.index(x_position, y_position)]
+    }
+
+    pub fn set(&mut self, x_position: usize, y_position: usize, cell: Cell) {
+        let index         = self.index(x_position, y_position);
+        self.counts.tally(self.cells[index], false);
+        self.counts.tally(cell, true);
+        for group in CellGroup::ALL {
+            match (group.contains(self.cells[index]), group.contains(cell)) {
+                (true, false) => self.positions[group.slot()].remove(index),
+                (false, true) => self.positions[group.slot()].insert(index),
+                _             => {}
+            }
+        }
+        self.cells[index] = cell;
+    }
+
+    pub fn neighbours(&self, x_position: usize, y_position: usize) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        NEIGHBOUR_OFFSETS.into_iter()
+                         .filter_map(move |(x_offset, y_offset)| {
+                             let x_neighbour = x_position.checked_add_signed(x_offset)?;
+                             let y_neighbour = y_position.checked_add_signed(y_offset)?;
+                             (x_neighbour < width && y_neighbour < height).then_some((x_neighbour, y_neighbour))
+                         })
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[Cell]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn counts(&self) -> PopulationCounts {
+        self.counts
+    }
+
+    pub fn count<P: Fn(Cell) -> bool>(&self, predicate: P) -> u64 {
+        self.cells.iter().filter(|cell| predicate(**cell)).count() as u64
+    }
+
+    pub fn group_size(&self, group: CellGroup) -> usize {
+        self.positions[group.slot()].members.len()
+    }
+
+    pub fn group_member(&self, group: CellGroup, n: usize) -> (usize, usize) {
+        self.position(self.positions[group.slot()].members[n] as usize)
+    }
+
+    pub(crate) fn position(&self, index: usize) -> (usize, usize) {
+        (index % self.width, index / self.width)
+    }
+
+    pub(crate) fn index(&self, x_position: usize, y_position: usize) -> usize {
+        assert!(x_position < self.width && y_position < self.height, "a ({}, {}) pozicio kivul esik a {}x{} meretu reten", x_position, y_position, self.width, self.height);
+        y_position * self.width + x_position
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cell::Animal;
+
+    #[test]
+    fn counts_follow_every_set() {
+        let mut grid = Grid::new(4, 3);
+        let infected = Animal { energy: 2, health: Health::Infected { remaining: 3 } };
+        let changes  = [
+            (0, 0, Cell::Fox(Animal::new(5))),
+            (1, 0, Cell::Rabbit(Animal::new(3))),
+            (2, 0, Cell::Grass),
+            (3, 2, Cell::Rabbit(infected)),
+            (1, 0, Cell::Fox(Animal { health: Health::Recovered, ..Animal::new(1) })),
+            (2, 0, Cell::Empty),
+            (3, 2, Cell::Rabbit(Animal::new(3))),
+            (0, 1, Cell::Grass)
+        ];
+
+        for (x_position, y_position, cell) in changes {
+            grid.set(x_position, y_position, cell);
+            assert_eq!(grid.get(x_position, y_position), cell);
+
+            let counts = grid.counts();
+            assert_eq!(counts.foxes, grid.count(Cell::is_fox));
+            assert_eq!(counts.rabbits, grid.count(Cell::is_rabbit));
+            assert_eq!(counts.grass, grid.count(|cell| cell == Cell::Grass));
+            assert_eq!(counts.empty, grid.count(Cell::is_empty));
+            assert_eq!(counts.susceptible, grid.count(|cell| cell.has_health(Health::is_susceptible)));
+            assert_eq!(counts.infected, grid.count(|cell| cell.has_health(Health::is_infected)));
+            assert_eq!(counts.recovered, grid.count(|cell| cell.has_health(Health::is_recovered)));
+            assert_eq!(counts.foxes + counts.rabbits + counts.grass + counts.empty, 12);
+        }
+    }
+
+    #[test]
+    fn group_members_follow_every_set() {
+        let mut grid = Grid::new(4, 3);
+        let changes  = [
+            (0, 0, Cell::Fox(Animal::new(5))),
+            (1, 1, Cell::Rabbit(Animal { energy: 2, health: Health::Infected { remaining: 3 } })),
+            (2, 2, Cell::Fox(Animal::new(1))),
+            (0, 0, Cell::Grass),
+            (1, 1, Cell::Rabbit(Animal::new(3))),
+            (3, 0, Cell::Rabbit(Animal::new(3)))
+        ];
+
+        for (x_position, y_position, cell) in changes {
+            grid.set(x_position, y_position, cell);
+            for group in CellGroup::ALL {
+                let mut members: Vec<(usize, usize)> = (0..grid.group_size(group)).map(|n| grid.group_member(group, n))
+                                                                                   .collect();
+                members.sort_by_key(|&(x_member, y_member)| (y_member, x_member));
+
+                let expected: Vec<(usize, usize)> = (0..grid.height()).flat_map(|y_member| (0..grid.width()).map(move |x_member| (x_member, y_member)))
+                                                                      .filter(|&(x_member, y_member)| group.contains(grid.get(x_member, y_member)))
+                                                                      .collect();
+                assert_eq!(members, expected, "{:?}", group);
+            }
+        }
+    }
+}
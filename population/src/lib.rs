@@ -0,0 +1,6 @@
+pub mod cell;
+pub mod command_line;
+pub mod disease;
+pub mod grid;
+pub mod simulation;
+pub mod termination;
@@ -1,32 +1,190 @@
 use crossterm::{
     event::{ self, Event, KeyCode },
     execute,
-    terminal::{ disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen },
-    cursor,
-    style::{ PrintStyledContent, Stylize }
+    terminal::{ disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen }
 };
-use std::{ error::Error, io, time::Duration, vec::Vec };
+use std::{ env, error::Error, fmt::Debug, io, ops::RangeInclusive, str::FromStr, time::Duration, vec::Vec };
 use tui::{
     backend::{ Backend, CrosstermBackend },
     layout::{ Alignment, Constraint, Direction, Layout },
-    widgets::{ Block, Borders, BarChart, BorderType, Paragraph },
+    widgets::{ Axis, Block, Borders, BarChart, BorderType, Chart, Dataset, GraphType, Paragraph },
     style::{ Color, Style, Modifier },
-    text::Span,
+    symbols::Marker,
+    text::{ Span, Spans },
     Frame,
     Terminal
 };
-use rand::Rng;
 use std::time::{ Duration as TimeDuration, Instant };
-
-const SIMULATION_SPACE_MAX_COLUMN_NUM: usize = 155;
-const SIMULATION_SPACE_MAX_ROW_NUM: usize    = 8;
+use population_lib::{ cell::Cell,
+                      command_line,
+                      disease::{ DiseaseParameters, Health },
+                      grid::Grid,
+                      simulation::{ BirthCounts, PopulationCounts, Simulation, SimulationParameters, UpdateScheme },
+                      termination::{ SimulationSummary, TerminationConditions, TerminationMonitor, TerminationReason } };
+
+const FALLBACK_GRID_SIZE: (usize, usize)         = (155, 8);
+const CHART_AREA_HEIGHT: u16                     = 43;
+const GRID_SCROLL_STEP: usize                    = 10;
 const POPULATION_CHART_MAX_COLUMN_NUM: usize     = 22;
 const FOX_AND_RABBIT_CHART_MAX_COLUMN_NUM: usize = 11;
+const SIR_CHART_MAX_POINT_NUM: usize             = 40;
+const GENERATIONS_PER_FRAME: f64                 = 1.0;
+
+struct ChartData {
+    population: Vec<(&'static str, u64)>,
+    foxes: Vec<(&'static str, u64)>,
+    rabbits: Vec<(&'static str, u64)>,
+    susceptible: Vec<(f64, f64)>,
+    infected: Vec<(f64, f64)>,
+    recovered: Vec<(f64, f64)>,
+    sample: u64,
+    counts: PopulationCounts,
+    births: BirthCounts,
+    time: f64,
+    fox_birth_label: String,
+    rabbit_birth_label: String
+}
+
+impl ChartData {
+    fn new(simulation: &Simulation) -> ChartData {
+        let mut chart_data = ChartData {
+            population: Vec::new(),
+            foxes: Vec::new(),
+            rabbits: Vec::new(),
+            susceptible: Vec::new(),
+            infected: Vec::new(),
+            recovered: Vec::new(),
+            sample: 0,
+            counts: simulation.counts(),
+            births: simulation.births(),
+            time: simulation.time(),
+            fox_birth_label: String::from("-"),
+            rabbit_birth_label: String::from("-")
+        };
+        chart_data.push_counts();
+        chart_data
+    }
+
+    fn record(&mut self, simulation: &Simulation) {
+        let births              = simulation.births();
+        let generations         = simulation.time() - self.time;
+        self.fox_birth_label    = birth_label(births.foxes - self.births.foxes, generations, self.counts.foxes);
+        self.rabbit_birth_label = birth_label(births.rabbits - self.births.rabbits, generations, self.counts.rabbits);
+        self.births             = births;
+        self.time               = simulation.time();
+
+        self.counts  = simulation.counts();
+        self.sample += 1;
+        self.push_counts();
+    }
+
+    fn push_counts(&mut self) {
+        let sample = self.sample as f64;
+        self.population.push(("", self.counts.animals()));
+        self.foxes.push(("", self.counts.foxes));
+        self.rabbits.push(("", self.counts.rabbits));
+        self.susceptible.push((sample, self.counts.susceptible as f64));
+        self.infected.push((sample, self.counts.infected as f64));
+        self.recovered.push((sample, self.counts.recovered as f64));
+    }
+
+    fn clear_full_charts(&mut self) {
+        if self.population.len() >= POPULATION_CHART_MAX_COLUMN_NUM {
+            self.population.clear();
+        }
+
+        if self.foxes.len() >= FOX_AND_RABBIT_CHART_MAX_COLUMN_NUM {
+            self.foxes.clear();
+            self.rabbits.clear();
+        }
+
+        if self.susceptible.len() >= SIR_CHART_MAX_POINT_NUM {
+            self.susceptible.clear();
+            self.infected.clear();
+            self.recovered.clear();
+        }
+    }
+}
+
+struct GridView {
+    x_offset: usize,
+    y_offset: usize
+}
+
+impl GridView {
+    fn scroll(&mut self, x_step: isize, y_step: isize) {
+        self.x_offset = self.x_offset.saturating_add_signed(x_step);
+        self.y_offset = self.y_offset.saturating_add_signed(y_step);
+    }
+
+    fn visible_range(&mut self, grid_length: usize, visible_length: usize, horizontal: bool) -> (usize, usize) {
+        let offset = if horizontal { &mut self.x_offset } else { &mut self.y_offset };
+        *offset    = (*offset).min(grid_length.saturating_sub(visible_length));
+        (*offset, (*offset + visible_length).min(grid_length))
+    }
+}
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let fox_birth_probability: f64        = read_data("🦊 szuletesenek valoszinusege (0.0..1.0):", "Hiba tortent a beolvasas soran!");
-    let fox_mortality_probability: f64    = read_data("🦊 halalozasanak valoszinusege (0.0..1.0):", "Hiba tortent a beolvasas soran!");
-    let rabbit_mortality_probability: f64 = read_data("🐰 halalozasanak valoszinusege (0.0..1.0):", "Hiba tortent a beolvasas soran!");
+    let options = match command_line::parse_arguments(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error_message) => {
+            eprintln!("{}\n\n{}", error_message, command_line::USAGE);
+            return Ok(());
+        }
+    };
+
+    if options.show_help {
+        println!("{}", command_line::USAGE);
+        return Ok(());
+    }
+
+    let (width, height) = options.grid_size.unwrap_or_else(fitted_grid_size);
+    let seed            = options.seed.unwrap_or_else(rand::random);
+
+    let defaults   = SimulationParameters::default();
+    let parameters = SimulationParameters {
+        fox_birth_probability: read_data("🦊 szuletesenek valoszinusege", 0.0..=1.0, defaults.fox_birth_probability, "Hiba tortent a beolvasas soran!"),
+        fox_mortality_probability: read_data("🦊 halalozasanak valoszinusege", 0.0..=1.0, defaults.fox_mortality_probability, "Hiba tortent a beolvasas soran!"),
+        rabbit_mortality_probability: read_data("🐰 halalozasanak valoszinusege", 0.0..=1.0, defaults.rabbit_mortality_probability, "Hiba tortent a beolvasas soran!"),
+        initial_grass_probability: read_data("🌱 kezdeti aranya a reten", 0.0..=1.0, defaults.initial_grass_probability, "Hiba tortent a beolvasas soran!"),
+        grass_regrowth_probability: read_data("🌱 visszanovesenek valoszinusege az ures helyeken", 0.0..=1.0, defaults.grass_regrowth_probability, "Hiba tortent a beolvasas soran!"),
+        rabbit_max_energy: read_data("🐰 ennyi lepest bir ki fu nelkul", 1..=1000, defaults.rabbit_max_energy, "Hiba tortent a beolvasas soran!"),
+        rabbit_breeding_probability: read_data("🐰 szaporodasanak valoszinusege", 0.0..=1.0, defaults.rabbit_breeding_probability, "Hiba tortent a beolvasas soran!"),
+        fox_energy_per_rabbit: read_data("🦊 energianyeresege egy nyul elfogyasztasakor", 1..=1000, defaults.fox_energy_per_rabbit, "Hiba tortent a beolvasas soran!"),
+        fox_breeding_energy: read_data("🦊 szaporodasahoz szukseges energia", 1..=1000, defaults.fox_breeding_energy, "Hiba tortent a beolvasas soran!"),
+        disease: DiseaseParameters {
+            initial_infection_probability: read_data("🦠 fertozott allatok kezdeti aranya", 0.0..=1.0, defaults.disease.initial_infection_probability, "Hiba tortent a beolvasas soran!"),
+            transmission_probability: read_data("🦠 atadasanak valoszinusege egy szomszedos allatra", 0.0..=1.0, defaults.disease.transmission_probability, "Hiba tortent a beolvasas soran!"),
+            recovery_time: read_data("🦠 gyogyulasi ideje (generacio)", 1..=1000, defaults.disease.recovery_time, "Hiba tortent a beolvasas soran!"),
+            mortality_probability: read_data("🦠 halalozasi valoszinusege a betegseg vegen", 0.0..=1.0, defaults.disease.mortality_probability, "Hiba tortent a beolvasas soran!"),
+            fox_susceptibility: read_data("🦊 fogekonysaga a betegsegre", 0.0..=1.0, defaults.disease.fox_susceptibility, "Hiba tortent a beolvasas soran!"),
+            rabbit_susceptibility: read_data("🐰 fogekonysaga a betegsegre", 0.0..=1.0, defaults.disease.rabbit_susceptibility, "Hiba tortent a beolvasas soran!")
+        },
+        update_scheme: read_update_scheme(defaults.update_scheme)
+    };
+
+    let termination_defaults = TerminationConditions::default();
+    let conditions           = TerminationConditions {
+        stop_on_extinction: read_data("⏹ leallas, ha valamelyik faj kihal (1: igen, 0: nem)", 0..=1, termination_defaults.stop_on_extinction as u8, "Hiba tortent a beolvasas soran!") == 1,
+        steady_state_generations: optional_limit(read_data("⏹ leallas, ha ennyi generacion at nem valtozik az allatok szama (0: nincs)", 0..=1_000_000_000, termination_defaults.steady_state_generations.unwrap_or(0), "Hiba tortent a beolvasas soran!")),
+        max_generations: optional_limit(read_data("⏹ generaciok maximalis szama (0: nincs korlat)", 0..=1_000_000_000, termination_defaults.max_generations.unwrap_or(0), "Hiba tortent a beolvasas soran!")),
+        wall_clock_limit: optional_limit(read_data("⏹ idokorlat masodpercben (0: nincs korlat)", 0..=604_800, termination_defaults.wall_clock_limit.map_or(0, |limit| limit.as_secs()), "Hiba tortent a beolvasas soran!"))
+                              .map(TimeDuration::from_secs)
+    };
+
+    let mut simulation = Simulation::new(width, height, parameters, seed);
+
+    if options.headless {
+        if conditions == (TerminationConditions { stop_on_extinction: false, steady_state_generations: None, max_generations: None, wall_clock_limit: None }) {
+            eprintln!("A TUI nelkuli futtatashoz legalabb egy leallasi feltetelt meg kell adni!");
+            return Ok(());
+        }
+
+        let summary = simulation.run(conditions);
+        println!("{}", summary.lines().join("\n"));
+        println!("\n{}", summary.save()?);
+        return Ok(());
+    }
 
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -35,7 +193,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     let backend      = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
     
-    let population_app_result = run_population_app(&mut terminal, fox_birth_probability, fox_mortality_probability, rabbit_mortality_probability);
+    let population_app_result = run_population_app(&mut terminal, simulation, conditions);
 
     disable_raw_mode()?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
@@ -50,318 +208,218 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-fn run_population_app<B: Backend>(terminal: &mut Terminal<B>, fox_birth_probability: f64, fox_mortality_probability: f64, rabbit_mortality_probability: f64) -> io::Result<()> {
-    let mut population: Vec<Vec<&str>> = vec![vec![" ";SIMULATION_SPACE_MAX_COLUMN_NUM];SIMULATION_SPACE_MAX_ROW_NUM];    
-    generate_population(&mut population, fox_birth_probability);
-
-    let mut population_data: Vec<(&str, u64)>        = Vec::new();
-    let mut fox_population_data: Vec<(&str, u64)>    = Vec::new();
-    let mut rabbit_population_data: Vec<(&str, u64)> = Vec::new();
+fn run_population_app<B: Backend>(terminal: &mut Terminal<B>, mut simulation: Simulation, conditions: TerminationConditions) -> io::Result<()> {
+    let mut monitor    = TerminationMonitor::new(conditions, &simulation);
+    let mut chart_data = ChartData::new(&simulation);
+    let mut grid_view  = GridView { x_offset: 0, y_offset: 0 };
     
     let mut timer: Instant = Instant::now();
 
-    population_data.push(("", get_symbol_count(&population, "R") + get_symbol_count(&population, "N")));
-    fox_population_data.push(("", get_symbol_count(&population, "R")));
-    rabbit_population_data.push(("", get_symbol_count(&population, "N")));
-
-    loop {
+    let reason = 'simulation: loop {
         if timer.elapsed() >= TimeDuration::from_secs(3) { 
-            population_data.push(("", get_symbol_count(&population, "R") + get_symbol_count(&population, "N")));
-            fox_population_data.push(("", get_symbol_count(&population, "R")));
-            rabbit_population_data.push(("", get_symbol_count(&population, "N")));
-            
+            chart_data.record(&simulation);
             timer = Instant::now();
         }     
 
-        terminal.draw(|frame| ui(frame, &population, &population_data, &fox_population_data, &rabbit_population_data))?;
-        
-        if population_data.len() >= POPULATION_CHART_MAX_COLUMN_NUM {
-            population_data.clear();
-        }
+        terminal.draw(|frame| ui(frame, simulation.grid(), &mut grid_view, &chart_data))?;
+        chart_data.clear_full_charts();
 
-        if fox_population_data.len() >= FOX_AND_RABBIT_CHART_MAX_COLUMN_NUM {
-            fox_population_data.clear();
-            rabbit_population_data.clear();
+        let frame_end = simulation.time() + GENERATIONS_PER_FRAME;
+        while simulation.time() < frame_end && !simulation.grid().is_empty() {
+            simulation.step();
+            if let Some(reason) = monitor.observe(&simulation) {
+                break 'simulation reason;
+            }
         }
 
-        animal_mortality(&mut population, fox_mortality_probability, rabbit_mortality_probability);
-        fox_eating(&mut population);
-
         if crossterm::event::poll(Duration::from_millis(100))? {
             if let Event::Key(key) = event::read()? {
-                if key.code == KeyCode::Esc {
-                    return Ok(());
+                match key.code {
+                    KeyCode::Esc   => break TerminationReason::Aborted,
+                    KeyCode::Left  => grid_view.scroll(-(GRID_SCROLL_STEP as isize), 0),
+                    KeyCode::Right => grid_view.scroll(GRID_SCROLL_STEP as isize, 0),
+                    KeyCode::Up    => grid_view.scroll(0, -1),
+                    KeyCode::Down  => grid_view.scroll(0, 1),
+                    KeyCode::Home  => grid_view = GridView { x_offset: 0, y_offset: 0 },
+                    _              => {}
                 }
                 if key.code == KeyCode::Char('s') || key.code == KeyCode::Char('S') {
                     event::read()?; 
                 }
             }
         }
-    }
+    };
+
+    run_summary_screen(terminal, &monitor.summary(&simulation, reason))
 }
 
-fn read_data(message: &str, error_message: &str) -> f64 {
+fn run_summary_screen<B: Backend>(terminal: &mut Terminal<B>, summary: &SimulationSummary) -> io::Result<()> {
+    let mut message = String::new();
+
     loop {
-        let mut data: String = String::new();
-    
-        println!("{}", message);
-        io::stdin()
-           .read_line(&mut data)
-           .expect(error_message);
-        
-        let value: f64 = data.trim()
-                             .parse::<f64>()
-                             .unwrap();
-
-        if value >= 0.0 && value <= 1.0  {
-            break value
-        } else {
-            println!("Az ertek csak 0.0..1.0 kozott lehet!")
+        terminal.draw(|frame| summary_ui(frame, summary, &message))?;
+
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Char('m') | KeyCode::Char('M') => {
+                    message = match summary.save() {
+                        Ok(file_name)      => format!("Mentve: {}", file_name),
+                        Err(error_message) => format!("Hiba a mentes soran: {}", error_message)
+                    };
+                }
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => return Ok(()),
+                _ => {}
+            }
         }
     }
 }
 
-fn generate_population(population: &mut Vec<Vec<&str>>, fox_birth_probability: f64) {
-    for simulation_space_row in population.iter_mut() {
-        for simulation_space_cell in simulation_space_row.iter_mut() {
-            let birth_probability: f64 = rand::thread_rng()
-                                              .gen_range(0.0..1.0);
-
-            if birth_probability < fox_birth_probability {
-                *simulation_space_cell = "R";
-            } else {
-                *simulation_space_cell = "N";
-            }
-        }
-    }    
+fn fitted_grid_size() -> (usize, usize) {
+    match crossterm::terminal::size() {
+        Ok((columns, rows)) => ((columns.saturating_sub(2) as usize).clamp(1, command_line::MAX_GRID_SIDE),
+                                (rows.saturating_sub(CHART_AREA_HEIGHT + 2) as usize).clamp(1, command_line::MAX_GRID_SIDE)),
+        Err(_)              => FALLBACK_GRID_SIZE
+    }
 }
 
-fn fox_eating(population: &mut Vec<Vec<&str>>) {
-    let x_position: usize = rand::thread_rng()
-                                 .gen_range(0..155);
-    let y_position: usize = rand::thread_rng()
-                                 .gen_range(0..8);
-
-    if population[y_position][x_position] == "R" {
-        if y_position < 1 && x_position < 1 {
-            if population[y_position][x_position + 1] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position][x_position + 1] = "R";
-            } else if population[y_position + 1][x_position] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position + 1][x_position] = "R";    
-            }
-        } else if y_position < 1 && x_position < SIMULATION_SPACE_MAX_COLUMN_NUM - 1 && x_position >= 1 {
-            if population[y_position][x_position + 1] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position][x_position + 1] = "R";
-            } else if population[y_position][x_position - 1] == "N"{
-                population[y_position][x_position]     = " ";
-                population[y_position][x_position - 1] = "R";
-            } else if population[y_position + 1][x_position] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position + 1][x_position] = "R";    
-            }
-        } else if x_position < 1 && y_position >= 1 && y_position < SIMULATION_SPACE_MAX_ROW_NUM - 1 {
-            if population[y_position - 1][x_position] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position - 1][x_position] = "R";  
-            } else if population[y_position + 1][x_position] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position + 1][x_position] = "R";  
-            } else if population[y_position][x_position + 1] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position][x_position + 1] = "R";  
-            }
-        } else if y_position + 1 >= SIMULATION_SPACE_MAX_ROW_NUM && x_position + 1 >= SIMULATION_SPACE_MAX_COLUMN_NUM {
-            if population[y_position][x_position - 1] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position][x_position - 1] = "R";
-            }
-            else if population[y_position - 1][x_position] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position - 1][x_position] = "R";
-            }
-        } else if y_position == SIMULATION_SPACE_MAX_ROW_NUM - 1 && x_position >= 1 && x_position < SIMULATION_SPACE_MAX_COLUMN_NUM - 1 {
-            if population[y_position][x_position - 1] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position][x_position - 1] = "R";
-            } else if population[y_position][x_position + 1] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position][x_position + 1] = "R";
-            } else if population[y_position - 1][x_position] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position - 1][x_position] = "R";
-            }
-        } else if x_position == SIMULATION_SPACE_MAX_COLUMN_NUM - 1 && y_position >= 1 && y_position < SIMULATION_SPACE_MAX_ROW_NUM - 1 {
-            if population[y_position - 1][x_position] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position - 1][x_position] = "R";
-            } else if population[y_position][x_position - 1] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position][x_position - 1] = "R";
-            } else if population[y_position + 1][x_position] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position + 1][x_position] = "R";
-            }
-        } else if y_position < 1 && x_position + 1 >= SIMULATION_SPACE_MAX_COLUMN_NUM {
-            if population[y_position][x_position - 1] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position][x_position - 1] = "R";
-            }
-            else if population[y_position + 1][x_position] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position + 1][x_position] = "R";
-            }
-        } else if x_position < 1 && y_position + 1 >= SIMULATION_SPACE_MAX_ROW_NUM {
-            if population[y_position][x_position + 1] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position][x_position + 1] = "R";
-            } else if population[y_position - 1][x_position] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position - 1][x_position] = "R";
-            }
-        } else {
-            if population[y_position - 1][x_position] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position - 1][x_position] = "R";
-            } else if population[y_position][x_position - 1] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position][x_position - 1] = "R";
-            } else if population[y_position + 1][x_position] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position + 1][x_position] = "R";
-            } else if population[y_position][x_position + 1] == "N" {
-                population[y_position][x_position]     = " ";
-                population[y_position][x_position + 1] = "R";
-            }
+fn read_update_scheme(default: UpdateScheme) -> UpdateScheme {
+    let (default_scheme, default_updates) = match default {
+        UpdateScheme::RandomAsynchronous { updates_per_tick } => (0, updates_per_tick),
+        UpdateScheme::Synchronous                             => (1, 1),
+        UpdateScheme::Gillespie                               => (2, 1)
+    };
+
+    match read_data("🔁 frissitesi mod (0: veletlen aszinkron, 1: szinkron generaciok, 2: Gillespie)", 0..=2, default_scheme, "Hiba tortent a beolvasas soran!") {
+        1 => UpdateScheme::Synchronous,
+        2 => UpdateScheme::Gillespie,
+        _ => UpdateScheme::RandomAsynchronous {
+            updates_per_tick: read_data("🔁 veletlen cellafrissitesek szama lepesenkent", 1..=100_000_000, default_updates, "Hiba tortent a beolvasas soran!")
         }
     }
 }
 
-fn animal_mortality(population: &mut Vec<Vec<&str>>, fox_mortality_probability: f64, rabbit_mortality_probability: f64) {
-    let x_position: usize = rand::thread_rng()
-                                 .gen_range(0..155);
-    let y_position: usize = rand::thread_rng()
-                                 .gen_range(0..8);
+fn optional_limit(value: u64) -> Option<u64> {
+    (value > 0).then_some(value)
+}
 
-    let rabbit_mortality: f64 = rand::thread_rng()
-                                     .gen_range(0.0..1.0);
-    let fox_mortality: f64    = rand::thread_rng()
-                                     .gen_range(0.0..1.0);
+fn read_data<T>(message: &str, range: RangeInclusive<T>, default: T, error_message: &str) -> T
+where
+    T: FromStr + PartialOrd + Debug + Copy
+{
+    loop {
+        let mut data: String = String::new();
     
-    if population[y_position][x_position] == "N" && rabbit_mortality < rabbit_mortality_probability {
-        population[y_position][x_position] = "F";
-    } else if population[y_position][x_position] == "R" && fox_mortality < fox_mortality_probability {
-        population[y_position][x_position] = "F";
-    }
-}
+        println!("{} ({:?}..{:?}, alapertek: {:?}):", message, range.start(), range.end(), default);
+        io::stdin()
+           .read_line(&mut data)
+           .expect(error_message);
 
-fn get_symbol_count(population: &Vec<Vec<&str>>, symbol: &str) -> u64 {
-    let mut symbol_count: u64 = 0;
+        if data.trim().is_empty() {
+            break default
+        }
 
-    for row in 0..SIMULATION_SPACE_MAX_ROW_NUM {
-        for cell in 0..SIMULATION_SPACE_MAX_COLUMN_NUM {
-            let symbol_value = population[row][cell];
-            if symbol_value == symbol {
-                symbol_count += 1;
-            } 
+        match data.trim().parse::<T>() {
+            Ok(value) if range.contains(&value) => break value,
+            _                                   => println!("Az ertek csak {:?}..{:?} kozott lehet!", range.start(), range.end())
         }
     }
-
-    symbol_count
 }
 
-fn print_symbol(simulation_space_cell: usize, simulation_space_row: usize, value: &str) {
-    if value == "R" {
-        execute!(io::stdout(),
-                 cursor::MoveTo((simulation_space_cell + 1) as u16, (simulation_space_row + 1) as u16),
-                 PrintStyledContent(value.with(crossterm::style::Color::DarkRed))).ok();
+fn birth_label(births: u64, generations: f64, population: u64) -> String {
+    if generations <= 0.0 {
+        return String::from("-");
     }
-    else if value == "N" {
-        execute!(io::stdout(),
-                 cursor::MoveTo((simulation_space_cell + 1) as u16, (simulation_space_row + 1) as u16),
-                 PrintStyledContent(value.with(crossterm::style::Color::Grey))).ok();
-    } else if value == "F" {
-        execute!(io::stdout(),
-                 cursor::MoveTo((simulation_space_cell + 1) as u16, (simulation_space_row + 1) as u16),
-                 PrintStyledContent(value.with(crossterm::style::Color::Green))).ok();
-    } else if value == " " {
-        execute!(io::stdout(),
-                 cursor::MoveTo((simulation_space_cell + 1) as u16, (simulation_space_row + 1) as u16),
-                 PrintStyledContent(value.with(crossterm::style::Color::Black))).ok();
+
+    let birth_rate = births as f64 / generations;
+    if population == 0 {
+        format!("{:.1} / generacio", birth_rate)
+    } else {
+        format!("{:.1} / generacio ({:.1}%)", birth_rate, birth_rate / population as f64 * 100.0)
     }
 }
 
-fn show_population(population: &Vec<Vec<&str>>) {
-    let mut value: &str;
-    for simulation_space_row in 0..SIMULATION_SPACE_MAX_ROW_NUM {
-        for simulation_space_cell in 0..SIMULATION_SPACE_MAX_COLUMN_NUM {
-            value = population[simulation_space_row][simulation_space_cell];
-            print_symbol(simulation_space_cell, simulation_space_row, value);     
-        }
+fn cell_color(cell: Cell) -> Color {
+    match cell {
+        _ if cell.has_health(Health::is_infected) => Color::Magenta,
+        Cell::Empty                               => Color::Black,
+        Cell::Fox(_)                              => Color::Red,
+        Cell::Rabbit(_)                           => Color::Gray,
+        Cell::Grass                               => Color::LightGreen
     }
 }
 
-fn clear_simulation_space() {
-    let clear_symbol = " ";
-    for simulation_space_row in 0..SIMULATION_SPACE_MAX_ROW_NUM {
-        for simulation_space_cell in 0..SIMULATION_SPACE_MAX_COLUMN_NUM {
-            print_symbol(simulation_space_cell, simulation_space_row, clear_symbol);     
-        }
-    }    
+fn grid_lines(grid: &Grid, columns: (usize, usize), rows: (usize, usize)) -> Vec<Spans<'_>> {
+    grid.rows()
+        .skip(rows.0)
+        .take(rows.1 - rows.0)
+        .map(|row| Spans::from(row[columns.0..columns.1].iter()
+                                                        .map(|cell| Span::styled(cell.symbol(), Style::default()
+                                                                                                      .fg(cell_color(*cell))))
+                                                        .collect::<Vec<Span>>()))
+        .collect()
 }
 
-fn ui<B: Backend>(frame: &mut Frame<B>, population: &Vec<Vec<&str>>, population_data: &Vec<(&str, u64)>, fox_population_data: &Vec<(&str, u64)>, rabbit_population_data: &Vec<(&str, u64)>) {
-    let main_screen = Layout::default()
+fn ui<B: Backend>(frame: &mut Frame<B>, grid: &Grid, grid_view: &mut GridView, chart_data: &ChartData) {
+    let meadow_height = (grid.height() as u16 + 2).min(frame.size().height.saturating_sub(CHART_AREA_HEIGHT)).max(3);
+    let main_screen   = Layout::default()
                              .direction(Direction::Vertical)
                              .constraints(
                                              [
-                                                 Constraint::Length(10),
+                                                 Constraint::Length(meadow_height),
                                                  Constraint::Length(20),
                                                  Constraint::Length(20),
-                                                 Constraint::Length(2)
+                                                 Constraint::Length(3)
                                              ]
                                              .as_ref(),
                              )
                              .split(frame.size());
     
+    let columns      = grid_view.visible_range(grid.width(), main_screen[0].width.saturating_sub(2) as usize, true);
+    let rows         = grid_view.visible_range(grid.height(), main_screen[0].height.saturating_sub(2) as usize, false);
+    let meadow_title = if columns == (0, grid.width()) && rows == (0, grid.height()) {
+        String::from("🦀 Populaciobiologiai Szimulacios Modell 🦀")
+    } else {
+        format!("🦀 Populaciobiologiai Szimulacios Modell 🦀 | oszlop: {}-{} / {} | sor: {}-{} / {}", columns.0 + 1, columns.1, grid.width(), rows.0 + 1, rows.1, grid.height())
+    };
+
     let simulation_space = Block::default()
-                                 .title(Span::styled("🦀 Populaciobiologiai Szimulacios Modell 🦀", Style::default()
-                                                                                                          .fg(Color::Cyan)
-                                                                                                          .add_modifier(Modifier::BOLD)))
+                                 .title(Span::styled(meadow_title, Style::default()
+                                                                         .fg(Color::Cyan)
+                                                                         .add_modifier(Modifier::BOLD)))
                                  .title_alignment(Alignment::Center)
                                  .borders(Borders::ALL)
                                  .border_type(BorderType::Rounded)
                                  .style(Style::default().fg(Color::LightGreen));
 
-    frame.render_widget(simulation_space, main_screen[0]);
-    clear_simulation_space();
-    show_population(&population);
+    let simulation_paragraph = Paragraph::new(grid_lines(grid, columns, rows))
+                                         .block(simulation_space);
+    frame.render_widget(simulation_paragraph, main_screen[0]);
         
     let chart_screen = Layout::default()
                               .direction(Direction::Horizontal)
                               .constraints(
                                   [
-                                      Constraint::Percentage(50),
-                                      Constraint::Percentage(50),
+                                      Constraint::Percentage(35),
+                                      Constraint::Percentage(35),
+                                      Constraint::Percentage(30),
                                   ]
                                   .as_ref(),
                               )
                               .split(main_screen[1]);
 
+    let fox_chart_title = format!(r#"🦊 populacio ("R") valtozasa | szuletes: {}"#, chart_data.fox_birth_label);
     let fox_chart: BarChart = BarChart::default()
                                        .block(Block::default()
-                                                    .title(Span::styled(r#"🦊 populacio ("R") szamanak valtozasa"#, Style::default()
-                                                                                                                          .fg(Color::Cyan)
-                                                                                                                          .add_modifier(Modifier::BOLD)))
+                                                    .title(Span::styled(fox_chart_title, Style::default()
+                                                                                               .fg(Color::Cyan)
+                                                                                               .add_modifier(Modifier::BOLD)))
                                                     .borders(Borders::ALL)
                                                     .border_type(BorderType::Rounded)
                                                     .border_style(Style::default()
                                                                         .fg(Color::LightGreen))
                                        )
-                                       .data(fox_population_data)
-                                       .bar_width(6)
+                                       .data(&chart_data.foxes)
+                                       .bar_width(4)
                                        .bar_style(Style::default()
                                                         .fg(Color::Red))
                                        .value_style(Style::default()
@@ -369,18 +427,19 @@ fn ui<B: Backend>(frame: &mut Frame<B>, population: &Vec<Vec<&str>>, population_
                                                           .add_modifier(Modifier::BOLD));
     frame.render_widget(fox_chart, chart_screen[0]);
 
+    let rabbit_chart_title = format!(r#"🐰 populacio ("N") valtozasa | szuletes: {}"#, chart_data.rabbit_birth_label);
     let rabbit_chart: BarChart = BarChart::default()
                                           .block(Block::default()
-                                                       .title(Span::styled(r#"🐰 populacio ("N") szamanak valtozasa"#, Style::default()
-                                                                                                                             .fg(Color::Cyan)
-                                                                                                                             .add_modifier(Modifier::BOLD)))
+                                                       .title(Span::styled(rabbit_chart_title, Style::default()
+                                                                                                     .fg(Color::Cyan)
+                                                                                                     .add_modifier(Modifier::BOLD)))
                                                        .borders(Borders::ALL)
                                                        .border_type(BorderType::Rounded)
                                                        .border_style(Style::default()
                                                                            .fg(Color::LightGreen))
                                           )
-                                          .data(rabbit_population_data)
-                                          .bar_width(6)
+                                          .data(&chart_data.rabbits)
+                                          .bar_width(4)
                                           .bar_style(Style::default()
                                                            .fg(Color::DarkGray))
                                           .value_style(Style::default()
@@ -388,6 +447,57 @@ fn ui<B: Backend>(frame: &mut Frame<B>, population: &Vec<Vec<&str>>, population_
                                                              .add_modifier(Modifier::BOLD));
     frame.render_widget(rabbit_chart, chart_screen[1]);
 
+    let sir_first_sample = chart_data.susceptible.first().map_or(0.0, |point| point.0);
+    let sir_max_count    = chart_data.susceptible
+                                     .iter()
+                                     .chain(&chart_data.infected)
+                                     .chain(&chart_data.recovered)
+                                     .fold(1.0, |max_count: f64, point| max_count.max(point.1));
+    let sir_datasets     = vec![
+        Dataset::default()
+                .name("S")
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default()
+                             .fg(Color::Gray))
+                .data(&chart_data.susceptible),
+        Dataset::default()
+                .name("I")
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default()
+                             .fg(Color::Magenta))
+                .data(&chart_data.infected),
+        Dataset::default()
+                .name("R")
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default()
+                             .fg(Color::LightBlue))
+                .data(&chart_data.recovered)
+    ];
+    let sir_chart_title  = format!("🦠 S: {} | I: {} | R: {}", chart_data.counts.susceptible, chart_data.counts.infected, chart_data.counts.recovered);
+    let sir_chart: Chart = Chart::new(sir_datasets)
+                                 .block(Block::default()
+                                              .title(Span::styled(sir_chart_title, Style::default()
+                                                                                         .fg(Color::Cyan)
+                                                                                         .add_modifier(Modifier::BOLD)))
+                                              .borders(Borders::ALL)
+                                              .border_type(BorderType::Rounded)
+                                              .border_style(Style::default()
+                                                                  .fg(Color::LightGreen))
+                                 )
+                                 .x_axis(Axis::default()
+                                              .style(Style::default()
+                                                           .fg(Color::DarkGray))
+                                              .bounds([sir_first_sample, sir_first_sample + SIR_CHART_MAX_POINT_NUM as f64]))
+                                 .y_axis(Axis::default()
+                                              .style(Style::default()
+                                                           .fg(Color::DarkGray))
+                                              .labels(vec![Span::from("0"), Span::from(format!("{}", sir_max_count))])
+                                              .bounds([0.0, sir_max_count]));
+    frame.render_widget(sir_chart, chart_screen[2]);
+
 
     let population_chart: BarChart = BarChart::default()
                                               .block(Block::default()
@@ -399,7 +509,7 @@ fn ui<B: Backend>(frame: &mut Frame<B>, population: &Vec<Vec<&str>>, population_
                                                            .border_style(Style::default()
                                                                                .fg(Color::LightGreen))
                                               )
-                                              .data(population_data)
+                                              .data(&chart_data.population)
                                               .bar_width(6)
                                               .bar_style(Style::default()
                                                                .fg(Color::LightYellow))
@@ -412,7 +522,7 @@ fn ui<B: Backend>(frame: &mut Frame<B>, population: &Vec<Vec<&str>>, population_
                              .direction(Direction::Vertical)
                              .constraints(
                                  [
-                                     Constraint::Length(2),
+                                     Constraint::Length(3),
                                  ]
                                  .as_ref(),
                              )
@@ -428,11 +538,61 @@ fn ui<B: Backend>(frame: &mut Frame<B>, population: &Vec<Vec<&str>>, population_
                            .border_type(BorderType::Rounded)
                            .borders(Borders::ALL);
 
-    let info_paragraph = Paragraph::new(Span::from("Kilepes: <ESC> | Szimulacio megallitasa / inditasa: <S>"))
+    let info_paragraph = Paragraph::new(Span::from("Leallitas es osszegzes: <ESC> | Szimulacio megallitasa / inditasa: <S> | Ret gorgetese: <NYILAK> | Vissza az elejere: <HOME>"))
                                    .style(Style::default()
                                                 .fg(Color::White))
                                    .block(info_block)
                                    .alignment(Alignment::Center);
 
     frame.render_widget(info_paragraph, info_screen[0]);
-}   
\ No newline at end of file
+}   
+
+fn summary_ui<B: Backend>(frame: &mut Frame<B>, summary: &SimulationSummary, message: &str) {
+    let summary_screen = Layout::default()
+                                .direction(Direction::Vertical)
+                                .constraints(
+                                    [
+                                        Constraint::Length(15),
+                                        Constraint::Length(3)
+                                    ]
+                                    .as_ref(),
+                                )
+                                .split(frame.size());
+
+    let summary_lines: Vec<Spans> = summary.lines()
+                                           .into_iter()
+                                           .map(Spans::from)
+                                           .collect();
+    let summary_paragraph = Paragraph::new(summary_lines)
+                                      .style(Style::default()
+                                                   .fg(Color::White))
+                                      .block(Block::default()
+                                                   .title(Span::styled("🦀 Szimulacio osszegzese 🦀", Style::default()
+                                                                                                         .fg(Color::Cyan)
+                                                                                                         .add_modifier(Modifier::BOLD)))
+                                                   .title_alignment(Alignment::Center)
+                                                   .borders(Borders::ALL)
+                                                   .border_type(BorderType::Rounded)
+                                                   .border_style(Style::default()
+                                                                       .fg(Color::LightGreen)));
+    frame.render_widget(summary_paragraph, summary_screen[0]);
+
+    let mut info_text = String::from("Mentes fajlba: <M> | Kilepes: <ESC>");
+    if !message.is_empty() {
+        info_text += &format!(" | {}", message);
+    }
+    let info_paragraph = Paragraph::new(Span::from(info_text))
+                                   .style(Style::default()
+                                                .fg(Color::White))
+                                   .block(Block::default()
+                                                .title(Span::styled("🚦 Muveletek 🚦", Style::default()
+                                                                                             .fg(Color::Cyan)
+                                                                                             .add_modifier(Modifier::BOLD)))
+                                                .title_alignment(Alignment::Center)
+                                                .border_style(Style::default()
+                                                                    .fg(Color::LightGreen))
+                                                .border_type(BorderType::Rounded)
+                                                .borders(Borders::ALL))
+                                   .alignment(Alignment::Center);
+    frame.render_widget(info_paragraph, summary_screen[1]);
+}
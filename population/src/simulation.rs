@@ -0,0 +1,667 @@
+use std::mem;
+use rand::{ Rng, SeedableRng, rngs::StdRng };
+use crate::{ cell::{ Animal, Cell },
+             disease::{ DiseaseParameters, Health },
+             grid::{ CellGroup, Grid },
+             termination::{ SimulationSummary, TerminationConditions, TerminationMonitor } };
+
+pub use crate::grid::PopulationCounts;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UpdateScheme {
+    RandomAsynchronous { updates_per_tick: u64 },
+    Synchronous,
+    Gillespie
+}
+
+impl UpdateScheme {
+    pub fn name(self) -> &'static str {
+        match self {
+            UpdateScheme::RandomAsynchronous { .. } => "veletlen aszinkron",
+            UpdateScheme::Synchronous               => "szinkron generaciok",
+            UpdateScheme::Gillespie                 => "Gillespie (folytonos ido)"
+        }
+    }
+}
+
+struct Rule {
+    apply: fn(&mut Simulation, usize, usize),
+    actors: &'static [CellGroup]
+}
+
+const RULES: [Rule; 6] = [
+    Rule { apply: Simulation::animal_mortality,    actors: &[CellGroup::Foxes, CellGroup::Rabbits] },
+    Rule { apply: Simulation::fox_hunting,         actors: &[CellGroup::Foxes] },
+    Rule { apply: Simulation::rabbit_grazing,      actors: &[CellGroup::Rabbits] },
+    Rule { apply: Simulation::rabbit_breeding,     actors: &[CellGroup::Rabbits] },
+    Rule { apply: Simulation::grass_regrowth,      actors: &[CellGroup::Empty] },
+    Rule { apply: Simulation::disease_progression, actors: &[CellGroup::Infected] }
+];
+
+const FATE_PHASE: u64     = 0;
+const BREEDING_PHASE: u64 = 1;
+const DISEASE_PHASE: u64  = 2;
+const RECOVERY_PHASE: u64 = 3;
+const PHASES: u64         = 4;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Fate {
+    Unchanged,
+    Regrows,
+    Dies,
+    Hunts(usize),
+    Grazes(usize),
+    Hungry
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SimulationParameters {
+    pub fox_birth_probability: f64,
+    pub fox_mortality_probability: f64,
+    pub rabbit_mortality_probability: f64,
+    pub initial_grass_probability: f64,
+    pub grass_regrowth_probability: f64,
+    pub rabbit_max_energy: u32,
+    pub rabbit_breeding_probability: f64,
+    pub fox_energy_per_rabbit: u32,
+    pub fox_breeding_energy: u32,
+    pub disease: DiseaseParameters,
+    pub update_scheme: UpdateScheme
+}
+
+impl Default for SimulationParameters {
+    fn default() -> SimulationParameters {
+        SimulationParameters {
+            fox_birth_probability: 0.2,
+            fox_mortality_probability: 0.05,
+            rabbit_mortality_probability: 0.1,
+            initial_grass_probability: 0.3,
+            grass_regrowth_probability: 0.5,
+            rabbit_max_energy: 5,
+            rabbit_breeding_probability: 0.5,
+            fox_energy_per_rabbit: 3,
+            fox_breeding_energy: 10,
+            disease: DiseaseParameters::default(),
+            update_scheme: UpdateScheme::RandomAsynchronous { updates_per_tick: 1 }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BirthCounts {
+    pub foxes: u64,
+    pub rabbits: u64
+}
+
+pub struct Simulation {
+    grid: Grid,
+    parameters: SimulationParameters,
+    rng: StdRng,
+    seed: u64,
+    tick: u64,
+    time: f64,
+    births: BirthCounts,
+    next_grid: Grid
+}
+
+impl Simulation {
+    pub fn new(width: usize, height: usize, parameters: SimulationParameters, seed: u64) -> Simulation {
+        let mut simulation = Simulation::with_grid(Grid::new(width, height), parameters, seed);
+        simulation.generate_population();
+        simulation
+    }
+
+    pub fn with_grid(grid: Grid, parameters: SimulationParameters, seed: u64) -> Simulation {
+        Simulation {
+            next_grid: grid.clone(),
+            grid,
+            parameters,
+            rng: StdRng::seed_from_u64(seed),
+            seed,
+            tick: 0,
+            time: 0.0,
+            births: BirthCounts::default()
+        }
+    }
+
+    pub fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    pub fn parameters(&self) -> &SimulationParameters {
+        &self.parameters
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    pub fn births(&self) -> BirthCounts {
+        self.births
+    }
+
+    pub fn counts(&self) -> PopulationCounts {
+        self.grid.counts()
+    }
+
+    pub fn step(&mut self) {
+        if !self.grid.is_empty() {
+            match self.parameters.update_scheme {
+                UpdateScheme::RandomAsynchronous { updates_per_tick } => self.random_asynchronous_update(updates_per_tick),
+                UpdateScheme::Synchronous                             => self.synchronous_update(),
+                UpdateScheme::Gillespie                               => self.gillespie_update()
+            }
+        }
+        self.tick += 1;
+    }
+
+    pub fn run(&mut self, conditions: TerminationConditions) -> SimulationSummary {
+        let mut monitor = TerminationMonitor::new(conditions, self);
+        loop {
+            self.step();
+            if let Some(reason) = monitor.observe(self) {
+                return monitor.summary(self, reason);
+            }
+        }
+    }
+
+    fn generate_population(&mut self) {
+        for y_position in 0..self.grid.height() {
+            for x_position in 0..self.grid.width() {
+                let cell = if self.chance(self.parameters.initial_grass_probability) {
+                    Cell::Grass
+                } else if self.chance(self.parameters.fox_birth_probability) {
+                    Cell::Fox(Animal::new(self.parameters.fox_energy_per_rabbit))
+                } else {
+                    Cell::Rabbit(Animal::new(self.parameters.rabbit_max_energy))
+                };
+                if cell.is_animal() && self.chance(self.parameters.disease.initial_infection_probability) {
+                    self.grid.set(x_position, y_position, cell.with_health(self.parameters.disease.infection()));
+                } else {
+                    self.grid.set(x_position, y_position, cell);
+                }
+            }
+        }
+    }
+
+    fn random_asynchronous_update(&mut self, updates_per_tick: u64) {
+        for _ in 0..updates_per_tick {
+            for rule in RULES {
+                let (x_position, y_position) = self.random_position();
+                (rule.apply)(self, x_position, y_position);
+            }
+        }
+        self.time = ((self.tick + 1) * updates_per_tick) as f64 / (self.grid.width() * self.grid.height()) as f64;
+    }
+
+    fn synchronous_update(&mut self) {
+        let generation_seed   = self.rng.gen();
+        let order: Vec<usize> = (0..self.grid.width() * self.grid.height()).collect();
+        let births            = Simulation::next_generation(&self.grid, &mut self.next_grid, &self.parameters, generation_seed, &order);
+
+        mem::swap(&mut self.grid, &mut self.next_grid);
+        self.births.foxes   += births.foxes;
+        self.births.rabbits += births.rabbits;
+        self.time           += 1.0;
+    }
+
+    fn next_generation(current: &Grid, next: &mut Grid, parameters: &SimulationParameters, generation_seed: u64, order: &[usize]) -> BirthCounts {
+        let mut fates = vec![Fate::Unchanged; order.len()];
+        for &index in order {
+            let mut rng  = cell_rng(generation_seed, index, FATE_PHASE);
+            fates[index] = match cell_at(current, index) {
+                Cell::Empty if roll(&mut rng, parameters.grass_regrowth_probability)        => Fate::Regrows,
+                Cell::Fox(_) if roll(&mut rng, parameters.fox_mortality_probability)       => Fate::Dies,
+                Cell::Rabbit(_) if roll(&mut rng, parameters.rabbit_mortality_probability) => Fate::Dies,
+                Cell::Fox(_)                                                               => first_neighbour(current, index, Cell::is_rabbit).map_or(Fate::Hungry, Fate::Hunts),
+                Cell::Rabbit(_)                                                            => first_neighbour(current, index, |cell| cell == Cell::Grass).map_or(Fate::Hungry, Fate::Grazes),
+                _                                                                          => Fate::Unchanged
+            };
+        }
+
+        let mut hunters = vec![None; order.len()];
+        for &index in order {
+            if let Fate::Hunts(prey) = fates[index] {
+                claim(&mut hunters[prey], index);
+            }
+        }
+        let mut grazers = vec![None; order.len()];
+        for &index in order {
+            if let Fate::Grazes(pasture) = fates[index] {
+                if hunters[index].is_none() {
+                    claim(&mut grazers[pasture], index);
+                }
+            }
+        }
+
+        for &index in order {
+            let left_behind = match (cell_at(current, index), fates[index]) {
+                (Cell::Grass, _) | (_, Fate::Regrows) | (_, Fate::Dies) => Cell::Grass,
+                _                                                     => Cell::Empty
+            };
+            set_at(next, index, left_behind);
+        }
+
+        let mut destinations = vec![None; order.len()];
+        for &index in order {
+            let cell = cell_at(current, index);
+            let Some(animal) = cell.animal() else {
+                continue;
+            };
+            if hunters[index].is_some() {
+                continue;
+            }
+
+            let arrival = match fates[index] {
+                Fate::Hunts(prey) if hunters[prey] == Some(index)        => Some((prey, animal.energy + parameters.fox_energy_per_rabbit)),
+                Fate::Grazes(pasture) if grazers[pasture] == Some(index) => Some((pasture, parameters.rabbit_max_energy)),
+                Fate::Dies                                               => None,
+                _                                                        => (animal.energy > 1).then_some((index, animal.energy - 1))
+            };
+            if let Some((destination, energy)) = arrival {
+                set_at(next, destination, cell.with_energy(energy));
+                destinations[index] = Some(destination);
+            }
+        }
+
+        let mut nurseries = vec![None; order.len()];
+        for &index in order {
+            let Some(destination) = destinations[index] else {
+                continue;
+            };
+            let mut rng = cell_rng(generation_seed, index, BREEDING_PHASE);
+            let breeds  = match cell_at(next, destination) {
+                Cell::Fox(fox)  => fox.energy >= parameters.fox_breeding_energy,
+                Cell::Rabbit(_) => roll(&mut rng, parameters.rabbit_breeding_probability),
+                _               => false
+            };
+
+            let free: Vec<usize> = neighbour_indices(next, destination).filter(|neighbour| cell_at(next, *neighbour).is_free())
+                                                                       .collect();
+            if breeds && !free.is_empty() {
+                claim(&mut nurseries[free[rng.gen_range(0..free.len())]], index);
+            }
+        }
+
+        let mut births = BirthCounts::default();
+        for &nursery in order {
+            let Some(destination) = nurseries[nursery].and_then(|parent| destinations[parent]) else {
+                continue;
+            };
+            match cell_at(next, destination) {
+                Cell::Fox(fox)  => {
+                    let cub = Animal::new(fox.energy / 2);
+                    set_at(next, destination, Cell::Fox(Animal { energy: fox.energy - cub.energy, ..fox }));
+                    set_at(next, nursery, Cell::Fox(cub));
+                    births.foxes += 1;
+                }
+                Cell::Rabbit(_) => {
+                    set_at(next, nursery, Cell::Rabbit(Animal::new(parameters.rabbit_max_energy)));
+                    births.rabbits += 1;
+                }
+                _               => {}
+            }
+        }
+
+        let mut infections = vec![false; order.len()];
+        for &index in order {
+            if !cell_at(current, index).is_infected() {
+                continue;
+            }
+            let mut rng = cell_rng(generation_seed, index, DISEASE_PHASE);
+            for neighbour in neighbour_indices(current, index) {
+                let neighbour_cell = cell_at(current, neighbour);
+                if neighbour_cell.has_health(Health::is_susceptible) && roll(&mut rng, parameters.disease.transmission_chance(neighbour_cell)) {
+                    infections[neighbour] = true;
+                }
+            }
+        }
+
+        for &index in order {
+            let Some(destination) = destinations[index] else {
+                continue;
+            };
+            let cell      = cell_at(next, destination);
+            let next_cell = match cell.animal().map(|animal| animal.health) {
+                Some(Health::Susceptible) if infections[index]        => cell.with_health(parameters.disease.infection()),
+                Some(Health::Infected { remaining }) if remaining > 1 => cell.with_health(Health::Infected { remaining: remaining - 1 }),
+                Some(Health::Infected { .. })                         => {
+                    if roll(&mut cell_rng(generation_seed, index, RECOVERY_PHASE), parameters.disease.mortality_probability) {
+                        Cell::Grass
+                    } else {
+                        cell.with_health(Health::Recovered)
+                    }
+                }
+                _                                                     => continue
+            };
+            set_at(next, destination, next_cell);
+        }
+
+        births
+    }
+
+    fn gillespie_update(&mut self) {
+        let end_time = self.time.floor() + 1.0;
+        loop {
+            let total_rate: usize = RULES.iter().map(|rule| self.actor_count(rule)).sum();
+            if total_rate == 0 {
+                break;
+            }
+
+            let time_step = -(1.0 - self.rng.gen_range(0.0..1.0_f64)).ln() / total_rate as f64;
+            if self.time + time_step >= end_time {
+                break;
+            }
+            self.time += time_step;
+
+            let mut chosen = self.rng.gen_range(0..total_rate);
+            'rules: for rule in RULES {
+                for &group in rule.actors {
+                    let group_size = self.grid.group_size(group);
+                    if chosen < group_size {
+                        let (x_position, y_position) = self.grid.group_member(group, chosen);
+                        (rule.apply)(self, x_position, y_position);
+                        break 'rules;
+                    }
+                    chosen -= group_size;
+                }
+            }
+        }
+        self.time = end_time;
+    }
+
+    fn actor_count(&self, rule: &Rule) -> usize {
+        rule.actors.iter()
+                   .map(|group| self.grid.group_size(*group))
+                   .sum()
+    }
+
+    fn chance(&mut self, probability: f64) -> bool {
+        roll(&mut self.rng, probability)
+    }
+
+    fn random_position(&mut self) -> (usize, usize) {
+        (self.rng.gen_range(0..self.grid.width()), self.rng.gen_range(0..self.grid.height()))
+    }
+
+    fn animal_mortality(&mut self, x_position: usize, y_position: usize) {
+        let mortality_probability = match self.grid.get(x_position, y_position) {
+            Cell::Fox(_)    => self.parameters.fox_mortality_probability,
+            Cell::Rabbit(_) => self.parameters.rabbit_mortality_probability,
+            _               => return
+        };
+
+        if self.chance(mortality_probability) {
+            self.grid.set(x_position, y_position, Cell::Grass);
+        }
+    }
+
+    fn find_neighbour<P: Fn(Cell) -> bool>(&self, x_position: usize, y_position: usize, predicate: P) -> Option<(usize, usize)> {
+        self.grid
+            .neighbours(x_position, y_position)
+            .find(|&(x_neighbour, y_neighbour)| predicate(self.grid.get(x_neighbour, y_neighbour)))
+    }
+
+    fn random_neighbour<P: Fn(Cell) -> bool>(&mut self, x_position: usize, y_position: usize, predicate: P) -> Option<(usize, usize)> {
+        let candidates: Vec<(usize, usize)> = self.grid
+                                                  .neighbours(x_position, y_position)
+                                                  .filter(|&(x_neighbour, y_neighbour)| predicate(self.grid.get(x_neighbour, y_neighbour)))
+                                                  .collect();
+        if candidates.is_empty() {
+            None
+        } else {
+            Some(candidates[self.rng.gen_range(0..candidates.len())])
+        }
+    }
+
+    fn fox_hunting(&mut self, mut x_position: usize, mut y_position: usize) {
+        let mut fox = match self.grid.get(x_position, y_position) {
+            Cell::Fox(fox) => fox,
+            _              => return
+        };
+
+        match self.find_neighbour(x_position, y_position, Cell::is_rabbit) {
+            Some((x_prey, y_prey)) => {
+                fox.energy += self.parameters.fox_energy_per_rabbit;
+                self.grid.set(x_position, y_position, Cell::Empty);
+                (x_position, y_position) = (x_prey, y_prey);
+            }
+            None if fox.energy <= 1 => {
+                self.grid.set(x_position, y_position, Cell::Empty);
+                return;
+            }
+            None => fox.energy -= 1
+        }
+
+        if fox.energy >= self.parameters.fox_breeding_energy {
+            if let Some((x_cub, y_cub)) = self.random_neighbour(x_position, y_position, Cell::is_free) {
+                let cub     = Animal::new(fox.energy / 2);
+                fox.energy -= cub.energy;
+                self.grid.set(x_cub, y_cub, Cell::Fox(cub));
+                self.births.foxes += 1;
+            }
+        }
+        self.grid.set(x_position, y_position, Cell::Fox(fox));
+    }
+
+    fn rabbit_grazing(&mut self, x_position: usize, y_position: usize) {
+        let mut rabbit = match self.grid.get(x_position, y_position) {
+            Cell::Rabbit(rabbit) => rabbit,
+            _                    => return
+        };
+
+        match self.find_neighbour(x_position, y_position, |cell| cell == Cell::Grass) {
+            Some((x_grass, y_grass)) => {
+                rabbit.energy = self.parameters.rabbit_max_energy;
+                self.grid.set(x_position, y_position, Cell::Empty);
+                self.grid.set(x_grass, y_grass, Cell::Rabbit(rabbit));
+            }
+            None if rabbit.energy <= 1 => self.grid.set(x_position, y_position, Cell::Empty),
+            None                       => {
+                rabbit.energy -= 1;
+                self.grid.set(x_position, y_position, Cell::Rabbit(rabbit));
+            }
+        }
+    }
+
+    fn rabbit_breeding(&mut self, x_position: usize, y_position: usize) {
+        if !self.grid.get(x_position, y_position).is_rabbit() || !self.chance(self.parameters.rabbit_breeding_probability) {
+            return;
+        }
+
+        if let Some((x_kit, y_kit)) = self.random_neighbour(x_position, y_position, Cell::is_free) {
+            self.grid.set(x_kit, y_kit, Cell::Rabbit(Animal::new(self.parameters.rabbit_max_energy)));
+            self.births.rabbits += 1;
+        }
+    }
+
+    fn grass_regrowth(&mut self, x_position: usize, y_position: usize) {
+        if self.grid.get(x_position, y_position) == Cell::Empty && self.chance(self.parameters.grass_regrowth_probability) {
+            self.grid.set(x_position, y_position, Cell::Grass);
+        }
+    }
+
+    fn disease_progression(&mut self, x_position: usize, y_position: usize) {
+        let cell      = self.grid.get(x_position, y_position);
+        let remaining = match cell.animal().map(|animal| animal.health) {
+            Some(Health::Infected { remaining }) => remaining,
+            _                                    => return
+        };
+
+        let neighbours: Vec<(usize, usize)> = self.grid.neighbours(x_position, y_position).collect();
+        for (x_neighbour, y_neighbour) in neighbours {
+            let neighbour = self.grid.get(x_neighbour, y_neighbour);
+            if !neighbour.has_health(Health::is_susceptible) {
+                continue;
+            }
+            if self.chance(self.parameters.disease.transmission_chance(neighbour)) {
+                self.grid.set(x_neighbour, y_neighbour, neighbour.with_health(self.parameters.disease.infection()));
+            }
+        }
+
+        let next_cell = if remaining > 1 {
+            cell.with_health(Health::Infected { remaining: remaining - 1 })
+        } else if self.chance(self.parameters.disease.mortality_probability) {
+            Cell::Grass
+        } else {
+            cell.with_health(Health::Recovered)
+        };
+        self.grid.set(x_position, y_position, next_cell);
+    }
+}
+
+fn roll(rng: &mut StdRng, probability: f64) -> bool {
+    rng.gen_range(0.0..1.0) < probability
+}
+
+fn cell_rng(generation_seed: u64, index: usize, phase: u64) -> StdRng {
+    StdRng::seed_from_u64(generation_seed ^ (index as u64 * PHASES + phase).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+}
+
+fn claim(claimant: &mut Option<usize>, index: usize) {
+    *claimant = Some(claimant.map_or(index, |current| current.min(index)));
+}
+
+fn cell_at(grid: &Grid, index: usize) -> Cell {
+    let (x_position, y_position) = grid.position(index);
+    grid.get(x_position, y_position)
+}
+
+fn set_at(grid: &mut Grid, index: usize, cell: Cell) {
+    let (x_position, y_position) = grid.position(index);
+    grid.set(x_position, y_position, cell);
+}
+
+fn neighbour_indices(grid: &Grid, index: usize) -> impl Iterator<Item = usize> + '_ {
+    let (x_position, y_position) = grid.position(index);
+    grid.neighbours(x_position, y_position)
+        .map(|(x_neighbour, y_neighbour)| grid.index(x_neighbour, y_neighbour))
+}
+
+fn first_neighbour<P: Fn(Cell) -> bool>(grid: &Grid, index: usize, predicate: P) -> Option<usize> {
+    neighbour_indices(grid, index).find(|neighbour| predicate(cell_at(grid, *neighbour)))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::seq::SliceRandom;
+    use super::*;
+
+    const WIDTH: usize  = 12;
+    const HEIGHT: usize = 8;
+
+    fn simulation(update_scheme: UpdateScheme, seed: u64) -> Simulation {
+        Simulation::new(WIDTH, HEIGHT, SimulationParameters { update_scheme, ..SimulationParameters::default() }, seed)
+    }
+
+    fn all_schemes() -> [UpdateScheme; 4] {
+        [
+            UpdateScheme::RandomAsynchronous { updates_per_tick: 1 },
+            UpdateScheme::RandomAsynchronous { updates_per_tick: (WIDTH * HEIGHT) as u64 },
+            UpdateScheme::Synchronous,
+            UpdateScheme::Gillespie
+        ]
+    }
+
+    #[test]
+    fn seeded_steps_are_deterministic() {
+        for update_scheme in all_schemes() {
+            let mut first  = simulation(update_scheme, 42);
+            let mut second = simulation(update_scheme, 42);
+            assert_eq!(first.grid(), second.grid());
+
+            for _ in 0..50 {
+                first.step();
+                second.step();
+                assert_eq!(first.grid(), second.grid());
+                assert_eq!(first.counts(), second.counts());
+                assert_eq!(first.births(), second.births());
+                assert_eq!(first.time(), second.time());
+            }
+        }
+    }
+
+    #[test]
+    fn time_advances_one_generation_per_generation_of_updates() {
+        for update_scheme in all_schemes() {
+            let steps_per_generation = match update_scheme {
+                UpdateScheme::RandomAsynchronous { updates_per_tick } => (WIDTH * HEIGHT) as u64 / updates_per_tick,
+                UpdateScheme::Synchronous | UpdateScheme::Gillespie   => 1
+            };
+
+            let mut simulation = simulation(update_scheme, 7);
+            for generation in 1..=5 {
+                for _ in 0..steps_per_generation {
+                    simulation.step();
+                }
+                assert_eq!(simulation.time(), generation as f64, "{}", update_scheme.name());
+            }
+        }
+    }
+
+    #[test]
+    fn synchronous_generation_does_not_depend_on_traversal_order() {
+        let parameters = SimulationParameters {
+            fox_mortality_probability: 0.0,
+            rabbit_mortality_probability: 0.0,
+            grass_regrowth_probability: 1.0,
+            rabbit_breeding_probability: 1.0,
+            fox_breeding_energy: 4,
+            disease: DiseaseParameters {
+                initial_infection_probability: 0.3,
+                transmission_probability: 1.0,
+                recovery_time: 1,
+                mortality_probability: 1.0,
+                fox_susceptibility: 1.0,
+                rabbit_susceptibility: 1.0
+            },
+            update_scheme: UpdateScheme::Synchronous,
+            ..SimulationParameters::default()
+        };
+        let simulation = Simulation::new(WIDTH, HEIGHT, parameters, 3);
+
+        let forward: Vec<usize>  = (0..WIDTH * HEIGHT).collect();
+        let backward: Vec<usize> = forward.iter().rev().copied().collect();
+        let mut shuffled         = forward.clone();
+        shuffled.shuffle(&mut StdRng::seed_from_u64(11));
+
+        let generations = [forward, backward, shuffled].map(|order| {
+            let mut next = simulation.grid().clone();
+            let births   = Simulation::next_generation(simulation.grid(), &mut next, simulation.parameters(), 5, &order);
+            (next, births)
+        });
+        assert_ne!(&generations[0].0, simulation.grid());
+        assert_eq!(generations[0], generations[1]);
+        assert_eq!(generations[0], generations[2]);
+    }
+
+    #[test]
+    fn synchronous_generation_reads_only_the_previous_state() {
+        let parameters = SimulationParameters {
+            fox_mortality_probability: 1.0,
+            rabbit_mortality_probability: 0.0,
+            grass_regrowth_probability: 0.0,
+            rabbit_breeding_probability: 0.0,
+            update_scheme: UpdateScheme::Synchronous,
+            ..SimulationParameters::default()
+        };
+        let mut grid = Grid::new(3, 1);
+        grid.set(0, 0, Cell::Rabbit(Animal::new(3)));
+        grid.set(1, 0, Cell::Fox(Animal::new(3)));
+        grid.set(2, 0, Cell::Rabbit(Animal::new(3)));
+
+        let mut simulation = Simulation::with_grid(grid, parameters, 1);
+        simulation.step();
+        assert_eq!(simulation.grid().get(0, 0), Cell::Rabbit(Animal::new(2)));
+        assert_eq!(simulation.grid().get(1, 0), Cell::Grass);
+        assert_eq!(simulation.grid().get(2, 0), Cell::Rabbit(Animal::new(2)));
+    }
+}
@@ -0,0 +1,245 @@
+use std::{ fs, io, time::{ Duration, Instant } };
+use crate::simulation::{ BirthCounts, PopulationCounts, Simulation, UpdateScheme };
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TerminationConditions {
+    pub stop_on_extinction: bool,
+    pub steady_state_generations: Option<u64>,
+    pub max_generations: Option<u64>,
+    pub wall_clock_limit: Option<Duration>
+}
+
+impl Default for TerminationConditions {
+    fn default() -> TerminationConditions {
+        TerminationConditions {
+            stop_on_extinction: true,
+            steady_state_generations: Some(10),
+            max_generations: None,
+            wall_clock_limit: None
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Species {
+    Fox,
+    Rabbit
+}
+
+impl Species {
+    pub fn name(self) -> &'static str {
+        match self {
+            Species::Fox    => "roka",
+            Species::Rabbit => "nyul"
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TerminationReason {
+    Extinction(Species),
+    SteadyState(u64),
+    MaxGenerations(u64),
+    WallClockLimit(Duration),
+    Aborted
+}
+
+impl TerminationReason {
+    pub fn description(self) -> String {
+        match self {
+            TerminationReason::Extinction(species)         => format!("kihalt a(z) {} populacio", species.name()),
+            TerminationReason::SteadyState(generations)    => format!("allandosult allapot: {} generacion at nem valtozott az allatok szama", generations),
+            TerminationReason::MaxGenerations(generations) => format!("elerte a generacioszam-korlatot ({} generacio)", generations),
+            TerminationReason::WallClockLimit(time)        => format!("elerte az idokorlatot ({} mp)", time.as_secs()),
+            TerminationReason::Aborted                     => String::from("a felhasznalo leallitotta")
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Extremes {
+    pub peak: u64,
+    pub peak_time: f64,
+    pub minimum: u64,
+    pub minimum_time: f64
+}
+
+impl Extremes {
+    fn new(value: u64, time: f64) -> Extremes {
+        Extremes {
+            peak: value,
+            peak_time: time,
+            minimum: value,
+            minimum_time: time
+        }
+    }
+
+    fn update(&mut self, value: u64, time: f64) {
+        if value > self.peak {
+            self.peak      = value;
+            self.peak_time = time;
+        }
+        if value < self.minimum {
+            self.minimum      = value;
+            self.minimum_time = time;
+        }
+    }
+}
+
+pub struct TerminationMonitor {
+    conditions: TerminationConditions,
+    started: Instant,
+    last_counts: PopulationCounts,
+    last_generation: u64,
+    unchanged_generations: u64,
+    foxes: Extremes,
+    rabbits: Extremes,
+    animals: Extremes,
+    fox_extinction: Option<f64>,
+    rabbit_extinction: Option<f64>
+}
+
+impl TerminationMonitor {
+    pub fn new(conditions: TerminationConditions, simulation: &Simulation) -> TerminationMonitor {
+        let counts = simulation.counts();
+        let time   = simulation.time();
+        TerminationMonitor {
+            conditions,
+            started: Instant::now(),
+            last_counts: counts,
+            last_generation: time as u64,
+            unchanged_generations: 0,
+            foxes: Extremes::new(counts.foxes, time),
+            rabbits: Extremes::new(counts.rabbits, time),
+            animals: Extremes::new(counts.animals(), time),
+            fox_extinction: (counts.foxes == 0).then_some(time),
+            rabbit_extinction: (counts.rabbits == 0).then_some(time)
+        }
+    }
+
+    pub fn observe(&mut self, simulation: &Simulation) -> Option<TerminationReason> {
+        let counts = simulation.counts();
+        let time   = simulation.time();
+
+        self.foxes.update(counts.foxes, time);
+        self.rabbits.update(counts.rabbits, time);
+        self.animals.update(counts.animals(), time);
+        if counts.foxes == 0 && self.fox_extinction.is_none() {
+            self.fox_extinction = Some(time);
+        }
+        if counts.rabbits == 0 && self.rabbit_extinction.is_none() {
+            self.rabbit_extinction = Some(time);
+        }
+
+        let generation = time as u64;
+        if generation > self.last_generation {
+            if (counts.foxes, counts.rabbits) == (self.last_counts.foxes, self.last_counts.rabbits) {
+                self.unchanged_generations += generation - self.last_generation;
+            } else {
+                self.unchanged_generations = 0;
+            }
+            self.last_counts     = counts;
+            self.last_generation = generation;
+        }
+
+        self.check(time)
+    }
+
+    fn check(&self, time: f64) -> Option<TerminationReason> {
+        if self.conditions.stop_on_extinction {
+            if self.fox_extinction.is_some() {
+                return Some(TerminationReason::Extinction(Species::Fox));
+            }
+            if self.rabbit_extinction.is_some() {
+                return Some(TerminationReason::Extinction(Species::Rabbit));
+            }
+        }
+        if let Some(generations) = self.conditions.steady_state_generations.filter(|generations| self.unchanged_generations >= *generations) {
+            return Some(TerminationReason::SteadyState(generations));
+        }
+        if let Some(max_generations) = self.conditions.max_generations.filter(|max_generations| time >= *max_generations as f64) {
+            return Some(TerminationReason::MaxGenerations(max_generations));
+        }
+        if let Some(limit) = self.conditions.wall_clock_limit.filter(|limit| self.started.elapsed() >= *limit) {
+            return Some(TerminationReason::WallClockLimit(limit));
+        }
+        None
+    }
+
+    pub fn summary(&self, simulation: &Simulation, reason: TerminationReason) -> SimulationSummary {
+        SimulationSummary {
+            reason,
+            seed: simulation.seed(),
+            update_scheme: simulation.parameters().update_scheme,
+            ticks: simulation.tick(),
+            time: simulation.time(),
+            elapsed: self.started.elapsed(),
+            final_counts: simulation.counts(),
+            births: simulation.births(),
+            foxes: self.foxes,
+            rabbits: self.rabbits,
+            animals: self.animals,
+            fox_extinction: self.fox_extinction,
+            rabbit_extinction: self.rabbit_extinction
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SimulationSummary {
+    pub reason: TerminationReason,
+    pub seed: u64,
+    pub update_scheme: UpdateScheme,
+    pub ticks: u64,
+    pub time: f64,
+    pub elapsed: Duration,
+    pub final_counts: PopulationCounts,
+    pub births: BirthCounts,
+    pub foxes: Extremes,
+    pub rabbits: Extremes,
+    pub animals: Extremes,
+    pub fox_extinction: Option<f64>,
+    pub rabbit_extinction: Option<f64>
+}
+
+impl SimulationSummary {
+    pub fn lines(&self) -> Vec<String> {
+        vec![
+            format!("Leallas oka: {}", self.reason.description()),
+            format!("Idotartam: t = {:.2} generacio ({} szimulacios lepes), {:.1} mp", self.time, self.ticks, self.elapsed.as_secs_f64()),
+            format!("Frissitesi mod: {}", self.update_scheme.name()),
+            format!("Seed: {}", self.seed),
+            format!("Vegso allapot: {} roka, {} nyul, {} fu, {} ures cella", self.final_counts.foxes, self.final_counts.rabbits, self.final_counts.grass, self.final_counts.empty),
+            births_line(self.births, self.time),
+            String::new(),
+            extremes_line("Roka", self.foxes),
+            extremes_line("Nyul", self.rabbits),
+            extremes_line("Osszes allat", self.animals),
+            String::new(),
+            format!("Roka kihalasa: {}", extinction_label(self.fox_extinction)),
+            format!("Nyul kihalasa: {}", extinction_label(self.rabbit_extinction))
+        ]
+    }
+
+    pub fn save(&self) -> io::Result<String> {
+        let file_name = format!("populacio_osszegzes_{}_{}.txt", self.seed, self.ticks);
+        fs::write(&file_name, self.lines().join("\n") + "\n")?;
+        Ok(file_name)
+    }
+}
+
+fn extremes_line(label: &str, extremes: Extremes) -> String {
+    format!("{}: csucs {} (t = {:.2}), minimum {} (t = {:.2})", label, extremes.peak, extremes.peak_time, extremes.minimum, extremes.minimum_time)
+}
+
+fn births_line(births: BirthCounts, time: f64) -> String {
+    if time > 0.0 {
+        format!("Szuletesek: {} roka ({:.2} / generacio), {} nyul ({:.2} / generacio)", births.foxes, births.foxes as f64 / time, births.rabbits, births.rabbits as f64 / time)
+    } else {
+        format!("Szuletesek: {} roka, {} nyul", births.foxes, births.rabbits)
+    }
+}
+
+fn extinction_label(extinction: Option<f64>) -> String {
+    extinction.map_or(String::from("nem halt ki"), |time| format!("t = {:.2}", time))
+}
@@ -0,0 +1,169 @@
+use std::{ cmp::Ordering, collections::VecDeque, fs, io };
+use rand::seq::SliceRandom;
+use crate::{ sort_algorithms::{ self, Algorithm, MAX_RANDOM_NUMBER },
+             sort_array::SortArray };
+
+const CASE_SEARCH_TRIALS: usize = 64;
+
+// M. D. McIlroy: "A Killer Adversary for Quicksort" (1999). Every item starts as
+// "gas" and is frozen to the next smallest "solid" value only when a comparison
+// forces it, which steers the pivot candidate towards the worst possible choice.
+struct KillerAdversary {
+    values: Vec<usize>,
+    gas: usize,
+    solid_count: usize,
+    candidate: usize
+}
+
+impl KillerAdversary {
+    fn new(size: usize) -> KillerAdversary {
+        KillerAdversary {
+            values: vec![size; size],
+            gas: size,
+            solid_count: 0,
+            candidate: 0
+        }
+    }
+
+    fn freeze(&mut self, item: usize) {
+        self.values[item] = self.solid_count;
+        self.solid_count += 1;
+    }
+
+    fn compare(&mut self, x_item: usize, y_item: usize) -> Ordering {
+        if self.values[x_item] == self.gas && self.values[y_item] == self.gas {
+            if x_item == self.candidate {
+                self.freeze(x_item);
+            } else {
+                self.freeze(y_item);
+            }
+        }
+
+        if self.values[x_item] == self.gas {
+            self.candidate = x_item;
+        } else if self.values[y_item] == self.gas {
+            self.candidate = y_item;
+        }
+
+        self.values[x_item].cmp(&self.values[y_item])
+    }
+}
+
+fn scale_ranks(ranks: &[usize]) -> Vec<u64> {
+    let step = (MAX_RANDOM_NUMBER / (ranks.len() as u64 + 1)).max(1);
+
+    ranks.iter()
+         .map(|rank| (*rank as u64 + 1) * step)
+         .collect()
+}
+
+fn killer_adversary_ranks(algorithm: Algorithm, size: usize, seed: u64) -> Vec<usize> {
+    let mut adversary = KillerAdversary::new(size);
+    let mut array     = SortArray::new((0..size).collect(),
+                                       |x_item: &usize, y_item: &usize| adversary.compare(*x_item, *y_item),
+                                       sort_algorithms::seeded_rng(seed));
+    algorithm.sort(&mut array);
+    drop(array);
+
+    adversary.values
+}
+
+fn pancake_worst_case_ranks(size: usize) -> Vec<usize> {
+    let mut ranks: Vec<usize> = (0..size.min(2)).collect();
+
+    for rank in 2..size {
+        let first_rank = ranks.remove(0);
+        ranks.push(rank);
+        ranks.push(first_rank);
+    }
+
+    ranks
+}
+
+fn searched_case_ranks(algorithm: Algorithm, size: usize, seed: u64, worst: bool) -> Vec<usize> {
+    let mut rng        = sort_algorithms::seeded_rng(seed);
+    let mut candidate  = if worst { (0..size).rev().collect::<Vec<usize>>() } else { (0..size).collect::<Vec<usize>>() };
+    let mut found      = candidate.clone();
+    let mut found_cost = None;
+
+    for _trial in 0..CASE_SEARCH_TRIALS {
+        let mut array = SortArray::new(candidate.clone(), usize::cmp, sort_algorithms::seeded_rng(seed));
+        algorithm.sort(&mut array);
+
+        let counters = array.counters();
+        let cost     = counters.comparisons + counters.swaps + counters.writes + counters.reversals;
+        if found_cost.is_none_or(|found_cost| if worst { cost > found_cost } else { cost < found_cost }) {
+            found_cost = Some(cost);
+            found      = candidate.clone();
+        }
+
+        candidate.shuffle(&mut rng);
+    }
+
+    found
+}
+
+fn balanced_insertion_ranks(size: usize) -> Vec<usize> {
+    let mut ranks  = Vec::with_capacity(size);
+    let mut ranges = VecDeque::from([(0, size)]);
+
+    while let Some((low, high)) = ranges.pop_front() {
+        if low < high {
+            let middle = low + (high - low) / 2;
+            ranks.push(middle);
+            ranges.push_back((low, middle));
+            ranges.push_back((middle + 1, high));
+        }
+    }
+
+    ranks
+}
+
+fn merge_sort_worst_case_ranks(ranks: &[usize]) -> Vec<usize> {
+    if ranks.len() < 2 {
+        return ranks.to_vec();
+    }
+
+    let left: Vec<usize>  = ranks.iter().skip(1).step_by(2).copied().collect();
+    let right: Vec<usize> = ranks.iter().step_by(2).copied().collect();
+
+    let mut worst_ranks = merge_sort_worst_case_ranks(&left);
+    worst_ranks.extend(merge_sort_worst_case_ranks(&right));
+    worst_ranks
+}
+
+pub fn best_case_input(algorithm: Algorithm, size: usize, seed: u64) -> Vec<u64> {
+    let ranks = match algorithm {
+        Algorithm::QuickSort => searched_case_ranks(algorithm, size, seed, false),
+        Algorithm::HeapSort  => (0..size).rev().collect(),
+        Algorithm::TreeSort  => balanced_insertion_ranks(size),
+        _                    => (0..size).collect()
+    };
+
+    scale_ranks(&ranks)
+}
+
+pub fn worst_case_input(algorithm: Algorithm, size: usize, seed: u64) -> Vec<u64> {
+    let ranks = match algorithm {
+        _ if algorithm.is_quick_sort_variant()    => killer_adversary_ranks(algorithm, size, seed),
+        Algorithm::CycleSort                      => (1..size).chain(0..size.min(1)).collect(),
+        Algorithm::PancakeSort                    => pancake_worst_case_ranks(size),
+        Algorithm::CombSort | Algorithm::HeapSort => searched_case_ranks(algorithm, size, seed, true),
+        Algorithm::TreeSort                       => (0..size).collect(),
+        Algorithm::MergeSort                      => merge_sort_worst_case_ranks(&(0..size).collect::<Vec<usize>>()),
+        _                                         => (0..size).rev().collect()
+    };
+
+    scale_ranks(&ranks)
+}
+
+pub fn save_input(algorithm: Algorithm, numbers: &[u64], seed: u64) -> io::Result<String> {
+    let file_name = format!("ellenseges_bemenet_{}_{}_{}.txt", algorithm.key(), numbers.len(), seed);
+    let content   = numbers.iter()
+                           .map(|number| number.to_string())
+                           .collect::<Vec<String>>()
+                           .join(" ");
+
+    fs::write(&file_name, content + "\n")?;
+    Ok(file_name)
+}
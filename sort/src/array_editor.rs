@@ -0,0 +1,267 @@
+use std::io;
+use crossterm::event::{ self, Event, KeyCode };
+use tui::{ backend::Backend,
+           layout::{ Layout, Direction, Constraint },
+           style::{ Color, Style, Modifier },
+           widgets::{ Block, Borders, BorderType, List, ListItem, Paragraph, Wrap },
+           text::{ Span, Spans },
+           Frame,
+           Terminal };
+use crate::{ input_data, sort_algorithms::list_type::StatefulList };
+
+enum EditorMode {
+    Browse,
+    EditValue,
+    InsertValue,
+    TypeArray,
+    LoadFile
+}
+
+struct ArrayEditor {
+    numbers: StatefulList<u64>,
+    mode: EditorMode,
+    input: String,
+    message: String
+}
+
+impl ArrayEditor {
+    fn new(input_numbers: &[u64]) -> ArrayEditor {
+        let mut numbers = StatefulList::with_items(input_numbers.to_vec());
+        if !numbers.items.is_empty() {
+            numbers.state.select(Some(0));
+        }
+
+        ArrayEditor {
+            numbers,
+            mode: EditorMode::Browse,
+            input: String::new(),
+            message: String::new()
+        }
+    }
+
+    fn start_input(&mut self, mode: EditorMode, input: String) {
+        self.mode    = mode;
+        self.input   = input;
+        self.message = String::new();
+    }
+
+    fn select_last(&mut self) {
+        let selected = self.numbers.items.len().checked_sub(1);
+        self.numbers.state.select(selected);
+    }
+
+    fn clear(&mut self) {
+        self.numbers.items.clear();
+        self.numbers.state.select(None);
+    }
+
+    fn delete_selected(&mut self) {
+        if let Some(index) = self.numbers.state.selected() {
+            self.numbers.items.remove(index);
+            if index >= self.numbers.items.len() {
+                self.select_last();
+            }
+        }
+    }
+
+    fn confirm_input(&mut self) {
+        let result = match self.mode {
+            EditorMode::Browse      => Ok(()),
+            EditorMode::EditValue   => self.parse_single_value().map(|value| {
+                if let Some(index) = self.numbers.state.selected() {
+                    self.numbers.items[index] = value;
+                }
+            }),
+            EditorMode::InsertValue => self.parse_single_value().map(|value| {
+                let index = self.numbers.state.selected().map_or(0, |index| index + 1);
+                self.numbers.items.insert(index, value);
+                self.numbers.state.select(Some(index));
+            }),
+            EditorMode::TypeArray   => input_data::parse_numbers(&self.input).map(|numbers| {
+                self.numbers.items = numbers;
+                self.numbers.state.select(if self.numbers.items.is_empty() { None } else { Some(0) });
+            }),
+            EditorMode::LoadFile    => input_data::load_numbers_from_file(&self.input).map(|numbers| {
+                self.numbers.items = numbers;
+                self.numbers.state.select(Some(0));
+            })
+        };
+
+        match result {
+            Ok(()) => {
+                self.mode    = EditorMode::Browse;
+                self.input   = String::new();
+                self.message = String::new();
+            }
+            Err(error_message) => self.message = error_message
+        }
+    }
+
+    fn parse_single_value(&self) -> Result<u64, String> {
+        match input_data::parse_numbers(&self.input)?.as_slice() {
+            [value] => Ok(*value),
+            _       => Err(String::from("Pontosan egy szamot kell megadni!"))
+        }
+    }
+
+    fn prompt(&self) -> &str {
+        match self.mode {
+            EditorMode::Browse      => "",
+            EditorMode::EditValue   => "Uj ertek: ",
+            EditorMode::InsertValue => "Beszurando ertek: ",
+            EditorMode::TypeArray   => "Szamok (szokozzel vagy vesszovel elvalasztva): ",
+            EditorMode::LoadFile    => "Fajl eleresi utja: "
+        }
+    }
+}
+
+fn array_editor_screen<B: Backend>(frame: &mut Frame<B>, editor: &mut ArrayEditor) {
+    let editor_layout = Layout::default()
+                               .direction(Direction::Vertical)
+                               .constraints(
+                                   [
+                                       Constraint::Min(5),
+                                       Constraint::Length(3),
+                                       Constraint::Length(3)
+                                   ]
+                                   .as_ref(),
+                               )
+                               .split(frame.size());
+
+    let upper_layout = Layout::default()
+                              .direction(Direction::Horizontal)
+                              .constraints(
+                                  [
+                                      Constraint::Percentage(40),
+                                      Constraint::Percentage(60)
+                                  ]
+                                  .as_ref(),
+                              )
+                              .split(editor_layout[0]);
+
+    let items: Vec<ListItem> = editor.numbers
+                                     .items
+                                     .iter()
+                                     .enumerate()
+                                     .map(|(index, number)| ListItem::new(format!("{:>5}: {}", index, number))
+                                                                     .style(Style::default()
+                                                                                  .fg(Color::White)
+                                                                                  .bg(Color::DarkGray)))
+                                     .collect();
+
+    let title = if editor.numbers.items.is_empty() {
+        String::from("Bemeneti tomb (ures: veletlen szamok)")
+    } else {
+        format!("Bemeneti tomb ({} elem)", editor.numbers.items.len())
+    };
+
+    let list_block = List::new(items)
+                          .block(Block::default()
+                                       .borders(Borders::ALL)
+                                       .border_type(BorderType::Rounded)
+                                       .border_style(Style::default()
+                                                           .fg(Color::Green))
+                                       .title(Span::styled(title, Style::default()
+                                                                         .fg(Color::Cyan)
+                                                                         .add_modifier(Modifier::BOLD))))
+                          .highlight_style(Style::default()
+                                                 .bg(Color::Cyan)
+                                                 .fg(Color::Black)
+                                                 .add_modifier(Modifier::BOLD))
+                          .highlight_symbol(">> ");
+    frame.render_stateful_widget(list_block, upper_layout[0], &mut editor.numbers.state);
+
+    let help_lines = vec![
+        Spans::from("Lepkedes: <FEL> / <LE>"),
+        Spans::from("Kivalasztott ertek szerkesztese: <ENTER>"),
+        Spans::from("Uj ertek beszurasa a kivalasztott utan: <A>"),
+        Spans::from("Kivalasztott ertek torlese: <DEL>"),
+        Spans::from("Teljes tomb begepelese / beillesztese: <T>"),
+        Spans::from("Betoltes fajlbol: <F>"),
+        Spans::from("Tomb torlese (veletlen szamok hasznalata): <C>"),
+        Spans::from(""),
+        Spans::from("Vissza a rendezesi algoritmusokhoz: <ESC>")
+    ];
+
+    let help_paragraph = Paragraph::new(help_lines)
+                                   .style(Style::default()
+                                                .fg(Color::White))
+                                   .block(Block::default()
+                                                .borders(Borders::ALL)
+                                                .border_type(BorderType::Rounded)
+                                                .border_style(Style::default()
+                                                                    .fg(Color::Green))
+                                                .title(Span::styled("Muveletek", Style::default()
+                                                                                       .fg(Color::Cyan)
+                                                                                       .add_modifier(Modifier::BOLD))))
+                                   .wrap(Wrap { trim: true });
+    frame.render_widget(help_paragraph, upper_layout[1]);
+
+    let input_paragraph = Paragraph::new(Spans::from(vec![
+                                             Span::styled(editor.prompt(), Style::default()
+                                                                                 .fg(Color::Cyan)),
+                                             Span::from(editor.input.as_str())
+                                         ]))
+                                    .style(Style::default()
+                                                 .fg(Color::White))
+                                    .block(Block::default()
+                                                 .borders(Borders::ALL)
+                                                 .border_type(BorderType::Rounded)
+                                                 .border_style(Style::default()
+                                                                     .fg(Color::Green))
+                                                 .title(Span::styled("Bevitel", Style::default()
+                                                                                      .fg(Color::Cyan)
+                                                                                      .add_modifier(Modifier::BOLD))));
+    frame.render_widget(input_paragraph, editor_layout[1]);
+
+    let message_paragraph = Paragraph::new(Span::from(editor.message.as_str()))
+                                      .style(Style::default()
+                                                   .fg(Color::LightRed))
+                                      .block(Block::default()
+                                                   .borders(Borders::ALL)
+                                                   .border_type(BorderType::Rounded)
+                                                   .border_style(Style::default()
+                                                                       .fg(Color::Green))
+                                                   .title(Span::styled("Uzenet", Style::default()
+                                                                                       .fg(Color::Cyan)
+                                                                                       .add_modifier(Modifier::BOLD))));
+    frame.render_widget(message_paragraph, editor_layout[2]);
+}
+
+pub fn run_array_editor<B: Backend>(terminal: &mut Terminal<B>, input_numbers: &mut Vec<u64>) -> io::Result<()> {
+    let mut editor = ArrayEditor::new(input_numbers);
+
+    loop {
+        terminal.draw(|frame| array_editor_screen(frame, &mut editor))?;
+
+        if let Event::Key(key) = event::read()? {
+            match editor.mode {
+                EditorMode::Browse => match key.code {
+                    KeyCode::Esc                                      => break,
+                    KeyCode::Down if !editor.numbers.items.is_empty() => editor.numbers.next(),
+                    KeyCode::Up if !editor.numbers.items.is_empty()   => editor.numbers.previous(),
+                    KeyCode::Enter => if let Some(index) = editor.numbers.state.selected() {
+                        let value = editor.numbers.items[index].to_string();
+                        editor.start_input(EditorMode::EditValue, value);
+                    }
+                    KeyCode::Char('a') | KeyCode::Char('A') | KeyCode::Insert => editor.start_input(EditorMode::InsertValue, String::new()),
+                    KeyCode::Char('t') | KeyCode::Char('T')                   => editor.start_input(EditorMode::TypeArray, String::new()),
+                    KeyCode::Char('f') | KeyCode::Char('F')                   => editor.start_input(EditorMode::LoadFile, String::new()),
+                    KeyCode::Char('c') | KeyCode::Char('C')                   => editor.clear(),
+                    KeyCode::Delete                                           => editor.delete_selected(),
+                    _ => {}
+                }
+                _ => match key.code {
+                    KeyCode::Esc       => editor.start_input(EditorMode::Browse, String::new()),
+                    KeyCode::Enter     => editor.confirm_input(),
+                    KeyCode::Backspace => { editor.input.pop(); }
+                    KeyCode::Char(character) => editor.input.push(character),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    *input_numbers = editor.numbers.items;
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use std::{ fs, hint, io, time };
+use rand::Rng;
+use crate::{ kernels,
+             sort_algorithms::{ self, Algorithm, MAX_RANDOM_NUMBER },
+             sort_array::SortArray };
+
+pub const BENCHMARK_SIZES: [usize; 6] = [4, 8, 16, 32, 64, 256];
+const ELEMENTS_PER_ROUND: usize       = 1 << 16;
+const BENCHMARK_ROUNDS: usize         = 7;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kernel {
+    TextbookInsertion,
+    TextbookQuick,
+    PlainInsertion,
+    BranchlessInsertion,
+    SortingNetwork,
+    PlainQuick,
+    BranchlessQuick,
+    StdUnstable
+}
+
+impl Kernel {
+    pub const ALL: [Kernel; 8] = [Kernel::TextbookInsertion, Kernel::TextbookQuick, Kernel::PlainInsertion, Kernel::BranchlessInsertion,
+                                  Kernel::SortingNetwork, Kernel::PlainQuick, Kernel::BranchlessQuick, Kernel::StdUnstable];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Kernel::TextbookInsertion   => "Beilleszteses rendezes (sort_algorithms, muszerezett)",
+            Kernel::TextbookQuick       => "Gyorsrendezes (sort_algorithms, muszerezett)",
+            Kernel::PlainInsertion      => "Beilleszteses rendezes (tankonyvi, szeleten)",
+            Kernel::BranchlessInsertion => "Beilleszteses rendezes (elagazasmentes binaris kereses)",
+            Kernel::SortingNetwork      => "Rendezohalozat (Batcher, 4 / 8 / 16 elemes blokkok)",
+            Kernel::PlainQuick          => "Gyorsrendezes (tankonyvi Lomuto, szeleten)",
+            Kernel::BranchlessQuick     => "Gyorsrendezes (elagazasmentes Lomuto)",
+            Kernel::StdUnstable         => "slice::sort_unstable (referencia)"
+        }
+    }
+
+    fn sort(self, numbers: &mut [u64]) {
+        match self {
+            Kernel::TextbookInsertion   => sort_instrumented(Algorithm::InsertSort, numbers),
+            Kernel::TextbookQuick       => sort_instrumented(Algorithm::QuickSort, numbers),
+            Kernel::PlainInsertion      => kernels::insertion_sort(numbers),
+            Kernel::BranchlessInsertion => kernels::branchless_insertion_sort(numbers),
+            Kernel::SortingNetwork      => kernels::network_sort(numbers),
+            Kernel::PlainQuick          => kernels::quick_sort(numbers),
+            Kernel::BranchlessQuick     => kernels::branchless_quick_sort(numbers),
+            Kernel::StdUnstable         => numbers.sort_unstable()
+        }
+    }
+}
+
+fn sort_instrumented(algorithm: Algorithm, numbers: &mut [u64]) {
+    let mut array = SortArray::new(numbers.to_vec(), u64::cmp, sort_algorithms::seeded_rng(0));
+    algorithm.sort(&mut array);
+    numbers.copy_from_slice(array.items());
+}
+
+pub struct BenchmarkRow {
+    pub kernel: Kernel,
+    pub nanos_per_array: Vec<Option<f64>>
+}
+
+fn measure(kernel: Kernel, inputs: &[u64], size: usize) -> Option<f64> {
+    let mut expected = inputs.to_vec();
+    for chunk in expected.chunks_mut(size) {
+        chunk.sort_unstable();
+    }
+
+    let arrays   = inputs.len() / size;
+    let mut best = f64::MAX;
+    for _ in 0..BENCHMARK_ROUNDS {
+        let mut numbers = inputs.to_vec();
+
+        let current_time = time::Instant::now();
+        for chunk in numbers.chunks_mut(size) {
+            kernel.sort(hint::black_box(chunk));
+        }
+        let nanos = current_time.elapsed().as_nanos() as f64;
+
+        if hint::black_box(&numbers) != &expected {
+            return None;
+        }
+        best = best.min(nanos / arrays as f64);
+    }
+
+    Some(best)
+}
+
+pub fn generate_benchmark(sizes: &[usize], seed: u64) -> Vec<BenchmarkRow> {
+    let mut rng = sort_algorithms::seeded_rng(seed);
+    let inputs: Vec<Vec<u64>> = sizes.iter()
+                                     .map(|size| {
+                                         let arrays = (ELEMENTS_PER_ROUND / size).max(1);
+                                         (0..arrays * size).map(|_| rng.gen_range(0..MAX_RANDOM_NUMBER)).collect()
+                                     })
+                                     .collect();
+
+    Kernel::ALL.iter()
+               .map(|kernel| BenchmarkRow {
+                   kernel: *kernel,
+                   nanos_per_array: sizes.iter()
+                                         .zip(&inputs)
+                                         .map(|(size, input)| measure(*kernel, input, *size))
+                                         .collect()
+               })
+               .collect()
+}
+
+fn cell(nanos: Option<f64>, baseline: Option<f64>) -> String {
+    match (nanos, baseline) {
+        (Some(nanos), Some(baseline)) => format!("{:.1} ns (x{:.2})", nanos, baseline / nanos),
+        (Some(nanos), None)           => format!("{:.1} ns", nanos),
+        (None, _)                     => String::from("HIBAS")
+    }
+}
+
+pub fn to_markdown(rows: &[BenchmarkRow], sizes: &[usize], seed: u64) -> String {
+    let mut markdown = format!("# Kis tombok rendezese: kernelek merese (seed: {})\n\n", seed);
+
+    if cfg!(debug_assertions) {
+        markdown += "Figyelem: debug build, a meres csak `cargo run --release -- --bench` mellett mervado.\n\n";
+    }
+    markdown += &format!("Tombonkenti ido, {} kor legjobbja, koronkent {} elem; zarojelben a gyorsulas a tankonyvi beilleszteses rendezeshez (szeleten) kepest.\n\n",
+                         BENCHMARK_ROUNDS, ELEMENTS_PER_ROUND);
+
+    let baseline = rows.iter().find(|row| row.kernel == Kernel::PlainInsertion);
+    let header: Vec<String> = sizes.iter().map(|size| format!("{} elem", size)).collect();
+    markdown += &format!("| Kernel | {} |\n", header.join(" | "));
+    markdown += &format!("|{}\n", "---|".repeat(sizes.len() + 1));
+    for row in rows {
+        let cells: Vec<String> = row.nanos_per_array
+                                    .iter()
+                                    .enumerate()
+                                    .map(|(index, nanos)| cell(*nanos, baseline.and_then(|baseline| baseline.nanos_per_array[index])))
+                                    .collect();
+        markdown += &format!("| {} | {} |\n", row.kernel.name(), cells.join(" | "));
+    }
+
+    markdown
+}
+
+pub fn save_markdown(rows: &[BenchmarkRow], sizes: &[usize], seed: u64) -> io::Result<String> {
+    let file_name = format!("kernel_meres_{}.md", seed);
+    fs::write(&file_name, to_markdown(rows, sizes, seed))?;
+    Ok(file_name)
+}
@@ -0,0 +1,297 @@
+use std::f64::consts::PI;
+use tui::{ backend::Backend,
+           layout::Rect,
+           style::{ Color, Style, Modifier },
+           symbols::Marker,
+           text::{ Span, Spans },
+           widgets::{ Block, BarChart, Paragraph, canvas::{ Canvas, Line, Points } },
+           Frame };
+use crate::sort_value::ChartValue;
+
+const LINE_DRAWING_LIMIT: usize = 360;
+const SPECTRUM_SYMBOL: &str     = "█";
+const CHART_MARGIN: f64         = 0.08;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChartView {
+    Bars,
+    DotPlot,
+    ColourSpectrum,
+    Circle,
+    Disparity
+}
+
+impl ChartView {
+    pub const ALL: [ChartView; 5] = [
+        ChartView::Bars,
+        ChartView::DotPlot,
+        ChartView::ColourSpectrum,
+        ChartView::Circle,
+        ChartView::Disparity
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ChartView::Bars           => "Oszlopdiagram",
+            ChartView::DotPlot        => "Pontdiagram",
+            ChartView::ColourSpectrum => "Szinspektrum",
+            ChartView::Circle         => "Kor",
+            ChartView::Disparity      => "Elteres a vegleges helytol"
+        }
+    }
+
+    pub fn key(self) -> &'static str {
+        match self {
+            ChartView::Bars           => "bars",
+            ChartView::DotPlot        => "dots",
+            ChartView::ColourSpectrum => "spectrum",
+            ChartView::Circle         => "circle",
+            ChartView::Disparity      => "disparity"
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<ChartView> {
+        ChartView::ALL.iter()
+                      .copied()
+                      .find(|view| view.key() == key)
+    }
+
+    pub fn next(self) -> ChartView {
+        let index = ChartView::ALL.iter()
+                                  .position(|view| *view == self)
+                                  .unwrap_or(0);
+        ChartView::ALL[(index + 1) % ChartView::ALL.len()]
+    }
+}
+
+pub fn render_chart<B: Backend, T: ChartValue>(frame: &mut Frame<B>, area: Rect, block: Block, values: &[T], view: ChartView) {
+    match view {
+        ChartView::Bars           => render_bars(frame, area, block, values),
+        ChartView::DotPlot        => render_dot_plot(frame, area, block, values),
+        ChartView::ColourSpectrum => render_colour_spectrum(frame, area, block, values),
+        ChartView::Circle         => render_circle(frame, area, block, values),
+        ChartView::Disparity      => render_disparity(frame, area, block, values)
+    }
+}
+
+fn render_bars<B: Backend, T: ChartValue>(frame: &mut Frame<B>, area: Rect, block: Block, values: &[T]) {
+    let Some(numbers) = values.iter().map(T::bar_value).collect::<Option<Vec<u64>>>() else {
+        render_signed_bars(frame, area, block, values);
+        return;
+    };
+    let tuple_vector: Vec<(&str, u64)> = numbers.iter()
+                                                .map(|number| ("", *number))
+                                                .collect();
+    let inner_width = area.width.saturating_sub(2) as usize;
+    let bar_gap     = if numbers.len() * 2 > inner_width { 0 } else { 1 };
+    let bar_width   = (inner_width / numbers.len().max(1))
+                          .saturating_sub(bar_gap)
+                          .clamp(1, 5) as u16;
+
+    let sort_chart = BarChart::default()
+                              .block(block)
+                              .data(&tuple_vector)
+                              .bar_width(bar_width)
+                              .bar_gap(bar_gap as u16)
+                              .bar_style(Style::default()
+                                               .fg(Color::LightGreen))
+                              .value_style(Style::default()
+                                                 .fg(Color::White)
+                                                 .add_modifier(Modifier::BOLD));
+    frame.render_widget(sort_chart, area);
+}
+
+fn render_signed_bars<B: Backend, T: ChartValue>(frame: &mut Frame<B>, area: Rect, block: Block, values: &[T]) {
+    let plot_values: Vec<f64> = values.iter().map(T::plot_value).collect();
+    let high   = plot_values.iter().copied().filter(|value| value.is_finite()).fold(0.0, f64::max);
+    let low    = plot_values.iter().copied().filter(|value| value.is_finite()).fold(0.0, f64::min);
+    let margin = if high > low { (high - low) * CHART_MARGIN } else { 1.0 };
+    let (top, bottom) = (high + margin, low - margin);
+
+    let inner_area     = block.inner(area);
+    let bar_columns    = inner_area.width as f64 / values.len().max(1) as f64;
+    let lines_per_bar  = ((bar_columns * 2.0 * 0.8) as usize).clamp(1, 8);
+    let show_labels    = values.iter().all(|value| (value.label().len() + 1) as f64 <= bar_columns);
+
+    let canvas = Canvas::default()
+                        .block(block)
+                        .marker(Marker::Braille)
+                        .x_bounds([0.0, values.len().max(1) as f64])
+                        .y_bounds([bottom, top])
+                        .paint(|context| {
+                            for (index, value) in plot_values.iter().enumerate() {
+                                let (y1, y2, color) = if value.is_nan() {
+                                    (bottom, top, Color::Magenta)
+                                } else if value.is_infinite() {
+                                    (0.0, if *value > 0.0 { top } else { bottom }, Color::Yellow)
+                                } else {
+                                    (0.0, *value, if *value < 0.0 { Color::LightRed } else { Color::LightGreen })
+                                };
+
+                                for line in 0..lines_per_bar {
+                                    let x = index as f64 + 0.1 + 0.8 * (line as f64 + 0.5) / lines_per_bar as f64;
+                                    context.draw(&Line { x1: x, y1, x2: x, y2, color });
+                                }
+                            }
+                            context.draw(&Line { x1: 0.0, y1: 0.0, x2: values.len() as f64, y2: 0.0, color: Color::Gray });
+
+                            if show_labels {
+                                context.layer();
+                                for (index, value) in values.iter().enumerate() {
+                                    let y = if plot_values[index].is_nan() { top } else { plot_values[index].clamp(bottom, top) };
+                                    context.print(index as f64 + 0.1,
+                                                  y,
+                                                  Span::styled(value.label(), Style::default()
+                                                                                    .fg(Color::White)
+                                                                                    .add_modifier(Modifier::BOLD)));
+                                }
+                            }
+                        });
+    frame.render_widget(canvas, area);
+}
+
+fn render_dot_plot<B: Backend, T: ChartValue>(frame: &mut Frame<B>, area: Rect, block: Block, values: &[T]) {
+    let coords: Vec<(f64, f64)> = plot_ratios(values).into_iter()
+                                                      .enumerate()
+                                                      .map(|(index, ratio)| (index as f64, ratio))
+                                                      .collect();
+
+    let canvas = Canvas::default()
+                        .block(block)
+                        .marker(Marker::Braille)
+                        .x_bounds([0.0, values.len().max(1) as f64])
+                        .y_bounds([0.0, 1.0])
+                        .paint(|context| {
+                            context.draw(&Points {
+                                coords: &coords,
+                                color: Color::LightGreen
+                            });
+                        });
+    frame.render_widget(canvas, area);
+}
+
+fn render_colour_spectrum<B: Backend, T: ChartValue>(frame: &mut Frame<B>, area: Rect, block: Block, values: &[T]) {
+    let inner_area = block.inner(area);
+    let ratios     = plot_ratios(values);
+
+    let strip: Vec<Span> = (0..inner_area.width as usize).filter(|_| !ratios.is_empty())
+                                                         .map(|column| {
+                                                             let index = column * ratios.len() / inner_area.width as usize;
+                                                             Span::styled(SPECTRUM_SYMBOL, Style::default()
+                                                                                                 .fg(hue_colour(ratios[index])))
+                                                         })
+                                                         .collect();
+    let lines: Vec<Spans> = (0..inner_area.height).map(|_| Spans::from(strip.clone()))
+                                                  .collect();
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn render_circle<B: Backend, T: ChartValue>(frame: &mut Frame<B>, area: Rect, block: Block, values: &[T]) {
+    let inner_area   = block.inner(area);
+    let aspect_ratio = inner_area.width as f64 / (2.0 * inner_area.height.max(1) as f64);
+    let ratios       = plot_ratios(values);
+
+    let endpoints: Vec<(f64, f64, Color)> = ratios.iter()
+                                                  .enumerate()
+                                                  .map(|(index, radius)| {
+                                                      let angle = 2.0 * PI * index as f64 / ratios.len() as f64;
+                                                      (radius * angle.cos(), radius * angle.sin(), hue_colour(*radius))
+                                                  })
+                                                  .collect();
+
+    let canvas = Canvas::default()
+                        .block(block)
+                        .marker(Marker::Braille)
+                        .x_bounds([-1.1 * aspect_ratio.max(1.0), 1.1 * aspect_ratio.max(1.0)])
+                        .y_bounds([-1.1 / aspect_ratio.min(1.0), 1.1 / aspect_ratio.min(1.0)])
+                        .paint(|context| {
+                            for (x, y, color) in &endpoints {
+                                if endpoints.len() <= LINE_DRAWING_LIMIT {
+                                    context.draw(&Line { x1: 0.0, y1: 0.0, x2: *x, y2: *y, color: *color });
+                                } else {
+                                    context.draw(&Points { coords: &[(*x, *y)], color: *color });
+                                }
+                            }
+                        });
+    frame.render_widget(canvas, area);
+}
+
+fn render_disparity<B: Backend, T: ChartValue>(frame: &mut Frame<B>, area: Rect, block: Block, values: &[T]) {
+    let distances    = final_position_distances(values);
+    let max_distance = distances.iter().copied().max().unwrap_or(0).max(1) as f64;
+
+    let canvas = Canvas::default()
+                        .block(block)
+                        .marker(Marker::Braille)
+                        .x_bounds([0.0, values.len().max(1) as f64])
+                        .y_bounds([0.0, max_distance])
+                        .paint(|context| {
+                            for (index, distance) in distances.iter().enumerate() {
+                                let color = hue_colour(*distance as f64 / max_distance);
+                                if distances.len() <= LINE_DRAWING_LIMIT {
+                                    context.draw(&Line { x1: index as f64, y1: 0.0, x2: index as f64, y2: *distance as f64, color });
+                                } else {
+                                    context.draw(&Points { coords: &[(index as f64, *distance as f64)], color });
+                                }
+                            }
+                        });
+    frame.render_widget(canvas, area);
+}
+
+fn plot_ratios<T: ChartValue>(values: &[T]) -> Vec<f64> {
+    let plot_values: Vec<f64> = values.iter().map(T::plot_value).collect();
+    let low    = plot_values.iter().copied().filter(|value| value.is_finite()).fold(0.0, f64::min);
+    let high   = plot_values.iter().copied().filter(|value| value.is_finite()).fold(low, f64::max);
+    let spread = if high > low { high - low } else { 1.0 };
+
+    plot_values.into_iter()
+               .map(|value| if value.is_nan() { 1.0 } else { ((value - low) / spread).clamp(0.0, 1.0) })
+               .collect()
+}
+
+fn final_position_distances<T: ChartValue>(values: &[T]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|i_index, j_index| values[*i_index].compare(&values[*j_index]));
+
+    let mut distances = vec![0; values.len()];
+    for (final_position, index) in order.into_iter().enumerate() {
+        distances[index] = final_position.abs_diff(index);
+    }
+
+    distances
+}
+
+fn hue_colour(ratio: f64) -> Color {
+    let hue       = ratio.clamp(0.0, 1.0) * 300.0 / 60.0;
+    let sector    = hue.floor() as u8;
+    let fraction  = hue - hue.floor();
+    let rising    = (255.0 * fraction) as u8;
+    let falling   = 255 - rising;
+
+    match sector {
+        0 => Color::Rgb(255, rising, 0),
+        1 => Color::Rgb(falling, 255, 0),
+        2 => Color::Rgb(0, 255, rising),
+        3 => Color::Rgb(0, falling, 255),
+        4 => Color::Rgb(rising, 0, 255),
+        _ => Color::Rgb(255, 0, 255)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_spreads_fill_the_plot_range() {
+        let ratios = plot_ratios(&[0.0, 0.0025, 0.005, 0.01]);
+        assert_eq!(ratios, vec![0.0, 0.25, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn equal_values_do_not_divide_by_zero() {
+        assert_eq!(plot_ratios(&[0u64, 0, 0]), vec![0.0, 0.0, 0.0]);
+        assert!(plot_ratios(&[3.5, 3.5]).iter().all(|ratio| ratio.is_finite()));
+    }
+}
@@ -0,0 +1,138 @@
+use std::str::FromStr;
+use crate::{ chart_view::ChartView, cost_model::{ self, CostModel }, signed_data::DataKind, sort_algorithms::{ Algorithm, MAX_ARRAY_SIZE } };
+
+pub const USAGE: &str = "Hasznalat: sort [--seed <szam>] [--size <elemszam>] [--view <nezet>] [--data <tipus>] [--cost <sulyok>] [--report] [--bench] [--wav <algoritmus>]
+
+Opciok:
+  -s, --seed <szam>       a veletlen bemenet generalasanak seed-je (0..18446744073709551615)
+  -n, --size <elemszam>   a veletlen / ellenseges bemenet merete (1..10000, alapertelmezett: 39)
+  -v, --view <nezet>      kezdo nezet: bars, dots, spectrum, circle, disparity
+  -d, --data <tipus>      az adatok tipusa: unsigned (u64), signed (i64), float (f64)
+  -c, --cost <sulyok>     a jelentes koltsegmodellje: osszehasonlitas,csere,iras,cache-hiba
+                          sulyai (alapertelmezett: 1,1,1,0)
+  -r, --report            a legjobb / atlagos / legrosszabb eset jelentes elkeszitese
+                          Markdown es CSV fajlba, a TUI inditasa nelkul
+  -b, --bench             a kis tombokre optimalizalt kernelek (elagazasmentes, rendezohalozat)
+                          merese a tankonyvi valtozatokkal szemben, a TUI inditasa nelkul
+  -w, --wav <algoritmus>  a megadott algoritmus futasanak hangfajlba (WAV) exportalasa
+                          a TUI inditasa nelkul (pl. quick, merge, heap)
+  -h, --help              ez a sugo";
+
+#[derive(Default)]
+pub struct CommandLineOptions {
+    pub seed: Option<u64>,
+    pub size: Option<usize>,
+    pub view: Option<ChartView>,
+    pub data_kind: Option<DataKind>,
+    pub cost_model: Option<CostModel>,
+    pub report: bool,
+    pub bench: bool,
+    pub wav_algorithm: Option<Algorithm>,
+    pub show_help: bool
+}
+
+pub fn parse_arguments<I: Iterator<Item = String>>(mut arguments: I) -> Result<CommandLineOptions, String> {
+    let mut options = CommandLineOptions::default();
+
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "-s" | "--seed" => options.seed = Some(parse_value(&argument, arguments.next())?),
+            "-n" | "--size" => options.size = Some(parse_size(&parse_value::<String>(&argument, arguments.next())?)?),
+            "-v" | "--view" => options.view = Some(parse_view(&parse_value::<String>(&argument, arguments.next())?)?),
+            "-d" | "--data" => options.data_kind = Some(parse_data_kind(&parse_value::<String>(&argument, arguments.next())?)?),
+            "-c" | "--cost" => options.cost_model = Some(cost_model::parse_cost_model(&parse_value::<String>(&argument, arguments.next())?)?),
+            "-r" | "--report" => options.report = true,
+            "-b" | "--bench"  => options.bench = true,
+            "-w" | "--wav"  => options.wav_algorithm = Some(parse_algorithm(&parse_value::<String>(&argument, arguments.next())?)?),
+            "-h" | "--help" => options.show_help = true,
+            _               => return Err(format!("Ismeretlen argumentum: {}", argument))
+        }
+    }
+
+    Ok(options)
+}
+
+pub fn parse_size(input: &str) -> Result<usize, String> {
+    match input.trim().parse::<usize>() {
+        Ok(size) if (1..=MAX_ARRAY_SIZE).contains(&size) => Ok(size),
+        _ => Err(format!("Ervenytelen tombmeret: \"{}\" (1..{})", input, MAX_ARRAY_SIZE))
+    }
+}
+
+fn parse_view(input: &str) -> Result<ChartView, String> {
+    ChartView::from_key(input).ok_or_else(|| format!("Ismeretlen nezet: {}", input))
+}
+
+fn parse_algorithm(input: &str) -> Result<Algorithm, String> {
+    Algorithm::from_key(input).ok_or_else(|| format!("Ismeretlen algoritmus: {}", input))
+}
+
+fn parse_data_kind(input: &str) -> Result<DataKind, String> {
+    DataKind::from_key(input).ok_or_else(|| format!("Ismeretlen adattipus: {}", input))
+}
+
+fn parse_value<T: FromStr>(argument: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("Hianyzo ertek: {}", argument))?;
+
+    value.parse::<T>()
+         .map_err(|_| format!("Ervenytelen ertek ({}): {}", argument, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(arguments: &[&str]) -> Result<CommandLineOptions, String> {
+        parse_arguments(arguments.iter().map(|argument| argument.to_string()))
+    }
+
+    #[test]
+    fn long_and_short_options_are_parsed() {
+        let options = parse(&["--seed", "42", "-n", "100", "--view", "circle", "-d", "float", "--report", "-b", "-w", "merge"]).unwrap();
+        assert_eq!(options.seed, Some(42));
+        assert_eq!(options.size, Some(100));
+        assert_eq!(options.view, Some(ChartView::Circle));
+        assert_eq!(options.data_kind, Some(DataKind::Float));
+        assert_eq!(options.wav_algorithm, Some(Algorithm::MergeSort));
+        assert!(options.report && options.bench && !options.show_help);
+
+        let options = parse(&["-s", "18446744073709551615", "--cost", "1,2,0.5,10", "-h"]).unwrap();
+        assert_eq!(options.seed, Some(u64::MAX));
+        assert_eq!(options.cost_model, Some(CostModel { comparison_weight: 1.0, swap_weight: 2.0, write_weight: 0.5, cache_miss_weight: 10.0 }));
+        assert!(options.show_help);
+    }
+
+    #[test]
+    fn missing_options_stay_unset() {
+        let options = parse(&[]).unwrap();
+        assert_eq!((options.seed, options.size, options.view, options.data_kind), (None, None, None, None));
+        assert!(options.cost_model.is_none() && options.wav_algorithm.is_none());
+        assert!(!options.report && !options.bench && !options.show_help);
+    }
+
+    #[test]
+    fn invalid_arguments_are_rejected() {
+        for arguments in [
+            &["--bogus"][..],
+            &["--seed"],
+            &["--seed", "-1"],
+            &["--seed", "abc"],
+            &["--size", "0"],
+            &["--size", "10001"],
+            &["--view", "pie"],
+            &["--data", "complex"],
+            &["--cost", "1,1,1"],
+            &["--wav", "bogo"]
+        ] {
+            assert!(parse(arguments).is_err(), "{:?}", arguments);
+        }
+    }
+
+    #[test]
+    fn size_is_trimmed_and_bounded() {
+        assert_eq!(parse_size(" 1 "), Ok(1));
+        assert_eq!(parse_size("10000"), Ok(MAX_ARRAY_SIZE));
+        assert!(parse_size("").is_err());
+        assert!(parse_size("12 elem").is_err());
+    }
+}
@@ -0,0 +1,157 @@
+use crate::sort_array::{ Counters, Operation };
+
+pub const CACHE_LINE_ITEMS: usize = 8;
+pub const CACHE_LINES: usize      = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CostModel {
+    pub comparison_weight: f64,
+    pub swap_weight: f64,
+    pub write_weight: f64,
+    pub cache_miss_weight: f64
+}
+
+impl Default for CostModel {
+    fn default() -> CostModel {
+        CostModel {
+            comparison_weight: 1.0,
+            swap_weight: 1.0,
+            write_weight: 1.0,
+            cache_miss_weight: 0.0
+        }
+    }
+}
+
+impl CostModel {
+    pub fn label(&self) -> String {
+        format!("osszehasonlitas: {}, csere: {}, iras: {}, cache-hiba: {}",
+                self.comparison_weight, self.swap_weight, self.write_weight, self.cache_miss_weight)
+    }
+
+    pub fn weights(&self) -> String {
+        format!("{} {} {} {}", self.comparison_weight, self.swap_weight, self.write_weight, self.cache_miss_weight)
+    }
+
+    pub fn weighted_cost(&self, counters: &Counters, cache: &CacheSimulator) -> f64 {
+        counters.comparisons as f64 * self.comparison_weight
+            + counters.swaps as f64 * self.swap_weight
+            + counters.writes as f64 * self.write_weight
+            + cache.misses as f64 * self.cache_miss_weight
+    }
+}
+
+pub fn parse_cost_model(input: &str) -> Result<CostModel, String> {
+    let weights: Vec<f64> = input.split(|character: char| character.is_whitespace() || character == ',')
+                                 .filter(|weight| !weight.is_empty())
+                                 .map(|weight| weight.parse::<f64>().ok().filter(|weight| weight.is_finite() && *weight >= 0.0))
+                                 .collect::<Option<Vec<f64>>>()
+                                 .ok_or_else(|| format!("Ervenytelen suly: \"{}\"", input))?;
+
+    match weights.as_slice() {
+        [comparison_weight, swap_weight, write_weight, cache_miss_weight] => Ok(CostModel {
+            comparison_weight: *comparison_weight,
+            swap_weight: *swap_weight,
+            write_weight: *write_weight,
+            cache_miss_weight: *cache_miss_weight
+        }),
+        _ => Err(format!("Negy nemnegativ suly szukseges (osszehasonlitas csere iras cache-hiba): \"{}\"", input))
+    }
+}
+
+#[derive(Default)]
+pub struct CacheSimulator {
+    lines: Vec<usize>,
+    accesses: u64,
+    misses: u64
+}
+
+impl CacheSimulator {
+    pub fn observe(&mut self, operation: Operation) {
+        match operation {
+            Operation::Compare(i_index, j_index) | Operation::Swap(i_index, j_index) => {
+                self.access(i_index);
+                self.access(j_index);
+            }
+            Operation::CompareValue(index) | Operation::Read(index) | Operation::Write(index) | Operation::CharRead(index, _) => self.access(index),
+            Operation::Reverse(low, high) => {
+                for index in low..high {
+                    self.access(index);
+                }
+            }
+            Operation::SiftUp(_) | Operation::SiftDown(_, _) | Operation::TreeInsert(_, _) |
+            Operation::ActiveRange(_, _) | Operation::Inserting(_) | Operation::Merging(_, _, _) | Operation::PivotPlaced(_) => {}
+        }
+    }
+
+    fn access(&mut self, index: usize) {
+        let line = index / CACHE_LINE_ITEMS;
+        self.accesses += 1;
+
+        match self.lines.iter().position(|cached_line| *cached_line == line) {
+            Some(position) => {
+                self.lines.remove(position);
+            }
+            None => {
+                self.misses += 1;
+                if self.lines.len() == CACHE_LINES {
+                    self.lines.remove(0);
+                }
+            }
+        }
+        self.lines.push(line);
+    }
+
+    pub fn accesses(&self) -> u64 {
+        self.accesses
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weights_are_parsed_from_commas_and_spaces() {
+        let expected = CostModel { comparison_weight: 1.0, swap_weight: 2.5, write_weight: 0.0, cache_miss_weight: 10.0 };
+        assert_eq!(parse_cost_model("1,2.5,0,10"), Ok(expected));
+        assert_eq!(parse_cost_model(" 1 2.5, 0 ,10 "), Ok(expected));
+        assert_eq!(parse_cost_model(&expected.weights()), Ok(expected));
+    }
+
+    #[test]
+    fn invalid_weights_are_rejected() {
+        for input in ["", "1,1,1", "1,1,1,1,1", "1,-1,1,1", "1,x,1,1", "1,inf,1,1", "NaN,1,1,1"] {
+            assert!(parse_cost_model(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn weighted_cost_combines_counters_and_cache_misses() {
+        let model    = CostModel { comparison_weight: 1.0, swap_weight: 2.0, write_weight: 3.0, cache_miss_weight: 10.0 };
+        let counters = Counters { comparisons: 5, swaps: 4, writes: 3, ..Counters::default() };
+
+        let mut cache = CacheSimulator::default();
+        cache.observe(Operation::Compare(0, CACHE_LINE_ITEMS));
+        cache.observe(Operation::Write(1));
+        assert_eq!((cache.accesses(), cache.misses()), (3, 2));
+
+        assert_eq!(model.weighted_cost(&counters, &cache), 5.0 + 8.0 + 9.0 + 20.0);
+    }
+
+    #[test]
+    fn least_recently_used_lines_are_evicted() {
+        let mut cache = CacheSimulator::default();
+        for line in 0..=CACHE_LINES {
+            cache.observe(Operation::Read(line * CACHE_LINE_ITEMS));
+        }
+        cache.observe(Operation::Read(CACHE_LINES * CACHE_LINE_ITEMS));
+        assert_eq!(cache.misses(), CACHE_LINES as u64 + 1);
+
+        cache.observe(Operation::Read(0));
+        assert_eq!(cache.misses(), CACHE_LINES as u64 + 2);
+    }
+}
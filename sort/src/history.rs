@@ -0,0 +1,478 @@
+use std::{ fs::{ self, OpenOptions }, io::{ self, Write }, time::{ SystemTime, UNIX_EPOCH } };
+use crossterm::event::{ self, Event, KeyCode };
+use tui::{ backend::Backend,
+           layout::{ Layout, Direction, Constraint },
+           style::{ Color, Style, Modifier },
+           widgets::{ Block, Borders, BorderType, Cell, Paragraph, Row, Table, TableState },
+           text::Span,
+           Frame,
+           Terminal };
+use crate::{ prompt,
+             signed_data::DataKind,
+             sort_algorithms::Algorithm,
+             linked_sort::ListAlgorithm,
+             sort_array::Counters,
+             string_sort::StringAlgorithm };
+
+const HISTORY_FILE: &str         = "futasi_elozmenyek.txt";
+const HISTORY_HEADER: [&str; 13] = ["Idopont (UTC)", "Algoritmus", "Bemenet", "Adattipus", "Meret", "Seed", "Osszehasonlitas", "Csere", "Iras", "Forditas", "Karakterolv.", "Mutato", "Ido (ms)"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputKind {
+    Random,
+    Adversary,
+    CustomArray,
+    BuiltinWords,
+    CustomWords
+}
+
+impl InputKind {
+    const ALL: [InputKind; 5] = [
+        InputKind::Random,
+        InputKind::Adversary,
+        InputKind::CustomArray,
+        InputKind::BuiltinWords,
+        InputKind::CustomWords
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            InputKind::Random       => "veletlen",
+            InputKind::Adversary    => "ellenseges",
+            InputKind::CustomArray  => "sajat tomb",
+            InputKind::BuiltinWords => "beepitett szolista",
+            InputKind::CustomWords  => "sajat szolista"
+        }
+    }
+
+    fn key(self) -> &'static str {
+        match self {
+            InputKind::Random       => "random",
+            InputKind::Adversary    => "adversary",
+            InputKind::CustomArray  => "custom",
+            InputKind::BuiltinWords => "words",
+            InputKind::CustomWords  => "custom_words"
+        }
+    }
+
+    fn from_key(key: &str) -> Option<InputKind> {
+        InputKind::ALL.iter()
+                      .copied()
+                      .find(|input_kind| input_kind.key() == key)
+    }
+
+    pub fn is_reproducible(self) -> bool {
+        matches!(self, InputKind::Random | InputKind::Adversary | InputKind::BuiltinWords)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecordedData {
+    Numbers(DataKind),
+    Text
+}
+
+impl RecordedData {
+    fn key(self) -> &'static str {
+        match self {
+            RecordedData::Numbers(data_kind) => data_kind.key(),
+            RecordedData::Text               => "string"
+        }
+    }
+
+    fn from_key(key: &str) -> Option<RecordedData> {
+        if key == RecordedData::Text.key() {
+            Some(RecordedData::Text)
+        } else {
+            DataKind::from_key(key).map(RecordedData::Numbers)
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RunRecord {
+    pub timestamp: u64,
+    pub algorithm_key: String,
+    pub input_kind: InputKind,
+    pub data: RecordedData,
+    pub size: usize,
+    pub seed: u64,
+    pub counters: Counters,
+    pub millis: u128
+}
+
+impl RunRecord {
+    pub fn new(algorithm_key: &str, input_kind: InputKind, data: RecordedData, size: usize, seed: u64, counters: Counters, millis: u128) -> RunRecord {
+        RunRecord {
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs()),
+            algorithm_key: algorithm_key.to_string(),
+            input_kind,
+            data,
+            size,
+            seed,
+            counters,
+            millis
+        }
+    }
+
+    fn parse(line: &str) -> Option<RunRecord> {
+        let mut fields: Vec<&str> = line.split(';').collect();
+        if fields.len() == 11 {
+            fields.push(RecordedData::Numbers(DataKind::Unsigned).key());
+        }
+        if fields.len() == 12 {
+            fields.push("0");
+        }
+
+        match fields.as_slice() {
+            [timestamp, algorithm_key, input_key, size, seed, comparisons, swaps, writes, reversals, character_reads, millis, data_key, pointer_updates] => Some(RunRecord {
+                timestamp: timestamp.parse().ok()?,
+                algorithm_key: algorithm_key.to_string(),
+                input_kind: InputKind::from_key(input_key)?,
+                data: if StringAlgorithm::from_key(algorithm_key).is_some() { RecordedData::Text } else { RecordedData::from_key(data_key)? },
+                size: size.parse().ok()?,
+                seed: seed.parse().ok()?,
+                counters: Counters {
+                    comparisons: comparisons.parse().ok()?,
+                    swaps: swaps.parse().ok()?,
+                    writes: writes.parse().ok()?,
+                    reversals: reversals.parse().ok()?,
+                    character_reads: character_reads.parse().ok()?,
+                    pointer_updates: pointer_updates.parse().ok()?
+                },
+                millis: millis.parse().ok()?
+            }),
+            _ => None
+        }
+    }
+
+    fn to_line(&self) -> String {
+        format!("{};{};{};{};{};{};{};{};{};{};{};{};{}",
+                self.timestamp, self.algorithm_key, self.input_kind.key(), self.size, self.seed,
+                self.counters.comparisons, self.counters.swaps, self.counters.writes, self.counters.reversals, self.counters.character_reads,
+                self.millis, self.data.key(), self.counters.pointer_updates)
+    }
+
+    pub fn algorithm_name(&self) -> &str {
+        Algorithm::from_key(&self.algorithm_key).map(Algorithm::name)
+                                                .or_else(|| StringAlgorithm::from_key(&self.algorithm_key).map(StringAlgorithm::name))
+                                                .or_else(|| ListAlgorithm::from_key(&self.algorithm_key).map(ListAlgorithm::name))
+                                                .unwrap_or(&self.algorithm_key)
+    }
+
+    fn cells(&self) -> [String; 13] {
+        [
+            format_timestamp(self.timestamp),
+            self.algorithm_name().to_string(),
+            self.input_kind.name().to_string(),
+            self.data.key().to_string(),
+            self.size.to_string(),
+            self.seed.to_string(),
+            self.counters.comparisons.to_string(),
+            self.counters.swaps.to_string(),
+            self.counters.writes.to_string(),
+            self.counters.reversals.to_string(),
+            self.counters.character_reads.to_string(),
+            self.counters.pointer_updates.to_string(),
+            self.millis.to_string()
+        ]
+    }
+}
+
+pub fn load_history() -> Vec<RunRecord> {
+    fs::read_to_string(HISTORY_FILE).map(|content| content.lines()
+                                                         .filter_map(RunRecord::parse)
+                                                         .collect())
+                                    .unwrap_or_default()
+}
+
+pub fn append_record(record: &RunRecord) -> io::Result<()> {
+    let mut history_file = OpenOptions::new().create(true)
+                                             .append(true)
+                                             .open(HISTORY_FILE)?;
+    history_file.write_all((record.to_line() + "\n").as_bytes())
+}
+
+fn format_timestamp(timestamp: u64) -> String {
+    let days           = (timestamp / 86400) as i64;
+    let seconds_of_day = timestamp % 86400;
+
+    let shifted_days  = days + 719468;
+    let era           = shifted_days.div_euclid(146097);
+    let day_of_era    = shifted_days.rem_euclid(146097);
+    let year_of_era   = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year   = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index   = (5 * day_of_year + 2) / 153;
+    let day           = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month         = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year          = year_of_era + era * 400 + (month <= 2) as i64;
+
+    format!("{:04}-{:02}-{:02} {:02}:{:02}", year, month, day, seconds_of_day / 3600, seconds_of_day % 3600 / 60)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SortKey {
+    Timestamp,
+    Algorithm,
+    Size,
+    Comparisons,
+    Swaps,
+    Millis
+}
+
+impl SortKey {
+    fn name(self) -> &'static str {
+        match self {
+            SortKey::Timestamp   => "idopont",
+            SortKey::Algorithm   => "algoritmus",
+            SortKey::Size        => "meret",
+            SortKey::Comparisons => "osszehasonlitas",
+            SortKey::Swaps       => "csere",
+            SortKey::Millis      => "ido"
+        }
+    }
+
+    fn next(self) -> SortKey {
+        match self {
+            SortKey::Timestamp   => SortKey::Algorithm,
+            SortKey::Algorithm   => SortKey::Size,
+            SortKey::Size        => SortKey::Comparisons,
+            SortKey::Comparisons => SortKey::Swaps,
+            SortKey::Swaps       => SortKey::Millis,
+            SortKey::Millis      => SortKey::Timestamp
+        }
+    }
+}
+
+struct HistoryBrowser {
+    records: Vec<RunRecord>,
+    visible: Vec<usize>,
+    state: TableState,
+    sort_key: SortKey,
+    descending: bool,
+    filter: String,
+    message: String
+}
+
+impl HistoryBrowser {
+    fn new() -> HistoryBrowser {
+        let mut browser = HistoryBrowser {
+            records: load_history(),
+            visible: Vec::new(),
+            state: TableState::default(),
+            sort_key: SortKey::Timestamp,
+            descending: true,
+            filter: String::new(),
+            message: String::new()
+        };
+        browser.refresh();
+        browser
+    }
+
+    fn refresh(&mut self) {
+        let filter = self.filter.to_lowercase();
+        self.visible = (0..self.records.len()).filter(|index| {
+                                                  let record = &self.records[*index];
+                                                  filter.is_empty()
+                                                      || record.algorithm_name().to_lowercase().contains(&filter)
+                                                      || record.algorithm_key.contains(&filter)
+                                                      || record.input_kind.name().contains(&filter)
+                                                      || record.data.key().contains(&filter)
+                                              })
+                                              .collect();
+
+        let records = &self.records;
+        self.visible.sort_by(|x_index, y_index| {
+            let (x_record, y_record) = (&records[*x_index], &records[*y_index]);
+            let ordering = match self.sort_key {
+                SortKey::Timestamp   => x_record.timestamp.cmp(&y_record.timestamp),
+                SortKey::Algorithm   => x_record.algorithm_name().cmp(y_record.algorithm_name()),
+                SortKey::Size        => x_record.size.cmp(&y_record.size),
+                SortKey::Comparisons => x_record.counters.comparisons.cmp(&y_record.counters.comparisons),
+                SortKey::Swaps       => x_record.counters.swaps.cmp(&y_record.counters.swaps),
+                SortKey::Millis      => x_record.millis.cmp(&y_record.millis)
+            };
+            if self.descending { ordering.reverse() } else { ordering }
+        });
+
+        self.state.select(if self.visible.is_empty() { None } else { Some(0) });
+    }
+
+    fn select_offset(&mut self, offset: isize) {
+        if let Some(selected) = self.state.selected() {
+            let selected = (selected as isize + offset).clamp(0, self.visible.len() as isize - 1);
+            self.state.select(Some(selected as usize));
+        }
+    }
+
+    fn selected_record(&self) -> Option<&RunRecord> {
+        self.state.selected().map(|selected| &self.records[self.visible[selected]])
+    }
+}
+
+fn history_screen<B: Backend>(frame: &mut Frame<B>, browser: &mut HistoryBrowser) {
+    let history_layout = Layout::default()
+                                .direction(Direction::Vertical)
+                                .constraints(
+                                    [
+                                        Constraint::Min(5),
+                                        Constraint::Length(3)
+                                    ]
+                                    .as_ref(),
+                                )
+                                .split(frame.size());
+
+    let header = Row::new(HISTORY_HEADER.iter().map(|title| Cell::from(*title)))
+                     .style(Style::default()
+                                  .fg(Color::Cyan)
+                                  .add_modifier(Modifier::BOLD));
+    let rows: Vec<Row> = browser.visible
+                                .iter()
+                                .map(|index| Row::new(browser.records[*index].cells()))
+                                .collect();
+
+    let widths = [
+        Constraint::Length(16),
+        Constraint::Length(36),
+        Constraint::Length(18),
+        Constraint::Length(9),
+        Constraint::Length(6),
+        Constraint::Length(20),
+        Constraint::Length(15),
+        Constraint::Length(8),
+        Constraint::Length(6),
+        Constraint::Length(8),
+        Constraint::Length(12),
+        Constraint::Length(8),
+        Constraint::Length(8)
+    ];
+
+    let title = format!("Futasi elozmenyek ({} / {} futas) | rendezes: {} {} | szuro: \"{}\"",
+                        browser.visible.len(), browser.records.len(), browser.sort_key.name(),
+                        if browser.descending { "csokkeno" } else { "novekvo" }, browser.filter);
+    let table = Table::new(rows)
+                      .header(header)
+                      .widths(&widths)
+                      .style(Style::default()
+                                   .fg(Color::White))
+                      .highlight_style(Style::default()
+                                             .bg(Color::Cyan)
+                                             .fg(Color::Black)
+                                             .add_modifier(Modifier::BOLD))
+                      .block(Block::default()
+                                   .borders(Borders::ALL)
+                                   .border_type(BorderType::Rounded)
+                                   .border_style(Style::default()
+                                                       .fg(Color::Green))
+                                   .title(Span::styled(title, Style::default()
+                                                                    .fg(Color::Cyan)
+                                                                    .add_modifier(Modifier::BOLD))));
+    frame.render_stateful_widget(table, history_layout[0], &mut browser.state);
+
+    let help_text = if browser.message.is_empty() {
+        "Fel / Le / PgUp / PgDn: gorgetes | S: rendezesi szempont | R: irany | F: szuro | Enter: ujrainditas a seed-del | ESC: vissza"
+    } else {
+        browser.message.as_str()
+    };
+    let help_paragraph = Paragraph::new(Span::from(help_text))
+                                   .style(Style::default()
+                                                .fg(Color::LightRed))
+                                   .block(Block::default()
+                                                .borders(Borders::ALL)
+                                                .border_type(BorderType::Rounded)
+                                                .border_style(Style::default()
+                                                                    .fg(Color::Green)));
+    frame.render_widget(help_paragraph, history_layout[1]);
+}
+
+pub fn run_history<B: Backend>(terminal: &mut Terminal<B>) -> io::Result<Option<RunRecord>> {
+    let mut browser = HistoryBrowser::new();
+
+    loop {
+        terminal.draw(|frame| history_screen(frame, &mut browser))?;
+
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Esc                            => return Ok(None),
+                KeyCode::Down                           => browser.select_offset(1),
+                KeyCode::Up                             => browser.select_offset(-1),
+                KeyCode::PageDown                       => browser.select_offset(20),
+                KeyCode::PageUp                         => browser.select_offset(-20),
+                KeyCode::Char('s') | KeyCode::Char('S') => {
+                    browser.sort_key = browser.sort_key.next();
+                    browser.refresh();
+                }
+                KeyCode::Char('r') | KeyCode::Char('R') => {
+                    browser.descending = !browser.descending;
+                    browser.refresh();
+                }
+                KeyCode::Char('f') | KeyCode::Char('F') => if let Some(filter) = prompt::read_value(terminal, "Szuro (algoritmus vagy bemenet, ures: nincs szuro)", &browser.filter, |input| Ok(input.trim().to_string()))? {
+                    browser.filter = filter;
+                    browser.refresh();
+                }
+                KeyCode::Enter => match browser.selected_record() {
+                    Some(record) if record.input_kind.is_reproducible() => return Ok(Some(record.clone())),
+                    Some(record) => browser.message = format!("A(z) \"{}\" bemenet nem allithato elo ujra a seed-bol.", record.input_kind.name()),
+                    None         => {}
+                },
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_survive_a_round_trip() {
+        let counters = Counters { comparisons: 10, swaps: 4, writes: 3, reversals: 2, character_reads: 1, pointer_updates: 7 };
+        let record   = RunRecord::new("merge", InputKind::Adversary, RecordedData::Numbers(DataKind::Float), 39, 2024, counters, 12);
+        let parsed   = RunRecord::parse(&record.to_line()).unwrap();
+
+        assert_eq!(parsed.timestamp, record.timestamp);
+        assert_eq!(parsed.algorithm_key, "merge");
+        assert_eq!(parsed.input_kind, InputKind::Adversary);
+        assert_eq!(parsed.data, RecordedData::Numbers(DataKind::Float));
+        assert_eq!((parsed.size, parsed.seed, parsed.millis), (39, 2024, 12));
+        assert_eq!(parsed.counters, counters);
+    }
+
+    #[test]
+    fn string_runs_keep_their_own_data_kind() {
+        let record = RunRecord::new("burst", InputKind::CustomWords, RecordedData::Text, 5, 1, Counters::default(), 0);
+        assert_eq!(RunRecord::parse(&record.to_line()).unwrap().data, RecordedData::Text);
+    }
+
+    #[test]
+    fn older_lines_without_data_kind_or_pointer_updates_are_read() {
+        let eleven_fields = RunRecord::parse("1700000000;quick;random;100;7;500;200;0;0;0;3").unwrap();
+        assert_eq!(eleven_fields.data, RecordedData::Numbers(DataKind::Unsigned));
+        assert_eq!(eleven_fields.counters, Counters { comparisons: 500, swaps: 200, ..Counters::default() });
+        assert_eq!(eleven_fields.millis, 3);
+
+        let eleven_field_strings = RunRecord::parse("1700000000;msd_radix;words;50;7;0;0;80;0;400;1").unwrap();
+        assert_eq!(eleven_field_strings.data, RecordedData::Text);
+        assert_eq!(eleven_field_strings.counters.character_reads, 400);
+
+        let twelve_fields = RunRecord::parse("1700000000;heap;custom;20;0;90;40;0;0;0;2;signed").unwrap();
+        assert_eq!(twelve_fields.input_kind, InputKind::CustomArray);
+        assert_eq!(twelve_fields.data, RecordedData::Numbers(DataKind::Signed));
+        assert_eq!(twelve_fields.counters.pointer_updates, 0);
+    }
+
+    #[test]
+    fn malformed_lines_are_skipped() {
+        for line in [
+            "",
+            "1700000000;quick;random;100;7;500;200;0;0;0",
+            "1700000000;quick;unknown;100;7;500;200;0;0;0;3",
+            "1700000000;quick;random;sok;7;500;200;0;0;0;3",
+            "1700000000;quick;random;100;7;500;200;0;0;0;3;complex",
+            "1700000000;quick;random;100;7;500;200;0;0;0;3;unsigned;0;extra"
+        ] {
+            assert!(RunRecord::parse(line).is_none(), "{}", line);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::{ fs, str::FromStr };
+
+fn parse_values<T: FromStr>(text: &str, separators: &[char]) -> Result<Vec<T>, String> {
+    text.split(|character: char| character.is_whitespace() || separators.contains(&character))
+        .filter(|token| !token.is_empty())
+        .map(|token| token.parse::<T>()
+                          .map_err(|_| format!("Ervenytelen szam: \"{}\"", token)))
+        .collect()
+}
+
+pub fn parse_numbers(text: &str) -> Result<Vec<u64>, String> {
+    parse_values(text, &[',', ';'])
+}
+
+pub fn parse_floats(text: &str) -> Result<Vec<f64>, String> {
+    parse_values(text, &[';'])
+}
+
+pub fn load_numbers_from_file(path: &str) -> Result<Vec<u64>, String> {
+    load_values_from_file(path, parse_numbers)
+}
+
+pub fn load_floats_from_file(path: &str) -> Result<Vec<f64>, String> {
+    load_values_from_file(path, parse_floats)
+}
+
+fn load_values_from_file<T>(path: &str, parse: fn(&str) -> Result<Vec<T>, String>) -> Result<Vec<T>, String> {
+    let path    = path.trim();
+    let content = fs::read_to_string(path)
+                     .map_err(|error| format!("A fajl nem olvashato ({}): {}", path, error))?;
+
+    let numbers = parse(&content)?;
+    if numbers.is_empty() {
+        return Err(format!("A fajl nem tartalmaz szamokat: {}", path));
+    }
+
+    Ok(numbers)
+}
+
+pub fn load_words_from_file(path: &str) -> Result<Vec<String>, String> {
+    let path    = path.trim();
+    let content = fs::read_to_string(path)
+                     .map_err(|error| format!("A fajl nem olvashato ({}): {}", path, error))?;
+
+    let words: Vec<String> = content.split_whitespace()
+                                    .map(String::from)
+                                    .collect();
+    if words.is_empty() {
+        return Err(format!("A fajl nem tartalmaz szavakat: {}", path));
+    }
+
+    Ok(words)
+}
@@ -0,0 +1,241 @@
+use std::cmp::Ordering;
+use crate::{ sort_algorithms::Algorithm, sort_array::Operation, sort_value::SortValue };
+
+pub fn invariant_description(algorithm: Algorithm) -> &'static str {
+    match algorithm {
+        Algorithm::SimpleSort | Algorithm::MinSort       => "a [0, i) prefix az i legkisebb elemet tartalmazza, rendezve",
+        Algorithm::BubbleSort | Algorithm::OptBubbleSort => "a [j, n) suffix a legnagyobb elemeket tartalmazza, vegleges helyukon",
+        Algorithm::CocktailSort                          => "a [0, also) es a [felso, n) resz vegleges helyen van",
+        Algorithm::PancakeSort                           => "a [meret, n) suffix vegleges helyen van",
+        Algorithm::HeapSort                              => "a kupac utani [meret, n) suffix vegleges helyen van",
+        Algorithm::InsertSort                            => "a [0, i] prefix a beszurando elemtol eltekintve rendezett",
+        Algorithm::MergeSort                             => "osszefesules elott mindket futam rendezett, a kiirt resz rendezett",
+        Algorithm::CycleSort                             => "a mar kiirt elemek vegleges helyukon vannak",
+        Algorithm::TreeSort                              => "a kiirt [0, k) prefix a k legkisebb elem, rendezve",
+        Algorithm::QuickSort | Algorithm::MedianQuickSort |
+        Algorithm::RandomQuickSort | Algorithm::IntroSort => "a mar elhelyezett pivotok vegleges helyukon vannak",
+        Algorithm::GnomeSort | Algorithm::CombSort |
+        Algorithm::OddEvenSort                           => "nincs ciklusinvarians, csak a vegeredmeny ellenorzott"
+    }
+}
+
+pub struct InvariantChecker<T> {
+    algorithm: Algorithm,
+    sorted: Vec<T>,
+    fixed: Vec<bool>,
+    active_range: (usize, usize),
+    insertion: Option<(usize, usize)>,
+    merge_low: usize,
+    checks: u64,
+    violation: Option<String>
+}
+
+impl<T: SortValue> InvariantChecker<T> {
+    pub fn new(algorithm: Algorithm, items: &[T]) -> InvariantChecker<T> {
+        let mut sorted = items.to_vec();
+        sorted.sort_by(T::compare);
+
+        InvariantChecker {
+            algorithm,
+            sorted,
+            fixed: vec![false; items.len()],
+            active_range: (0, items.len()),
+            insertion: None,
+            merge_low: 0,
+            checks: 0,
+            violation: None
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        invariant_description(self.algorithm)
+    }
+
+    pub fn checks(&self) -> u64 {
+        self.checks
+    }
+
+    pub fn violation(&self) -> Option<&str> {
+        self.violation.as_deref()
+    }
+
+    pub fn update(&mut self, operation: Operation, items: &[T]) {
+        if self.violation.is_some() {
+            return;
+        }
+
+        match (self.algorithm, operation) {
+            (Algorithm::SimpleSort | Algorithm::MinSort | Algorithm::BubbleSort | Algorithm::OptBubbleSort |
+             Algorithm::CocktailSort | Algorithm::PancakeSort, Operation::ActiveRange(low, high)) => self.shrink_active_range(low, high, operation, items),
+            (Algorithm::HeapSort, Operation::SiftDown(_, heap_size)) => self.shrink_active_range(0, heap_size, operation, items),
+            (Algorithm::InsertSort, Operation::Inserting(index)) => {
+                if let Some((_, moving)) = self.insertion {
+                    self.check_neighbours(moving, index, operation, items);
+                }
+                self.insertion = Some((index, index));
+            }
+            (Algorithm::InsertSort, Operation::Swap(i_index, j_index)) => if let Some((end, moving)) = self.insertion {
+                if moving == i_index || moving == j_index {
+                    self.insertion = Some((end, i_index + j_index - moving));
+                } else {
+                    self.check_sorted_except(end + 1, moving, operation, items);
+                }
+            }
+            (Algorithm::MergeSort, Operation::Merging(low, middle, high)) => {
+                self.check_sorted_run(low, middle, operation, items);
+                self.check_sorted_run(middle, high, operation, items);
+                self.merge_low = low;
+            }
+            (Algorithm::MergeSort, Operation::Write(position)) if position > self.merge_low => {
+                self.checks += 1;
+                if items[position - 1].compare(&items[position]) == Ordering::Greater {
+                    self.violate(format!("a kiirt resz nem rendezett: a(z) {}. elem ({}) nagyobb a(z) {}. elemnel ({})",
+                                         position - 1, items[position - 1].label(), position, items[position].label()), operation);
+                }
+            }
+            (_, Operation::PivotPlaced(index)) if self.algorithm.is_quick_sort_variant() => self.fix(index, operation, items),
+            (Algorithm::CycleSort | Algorithm::TreeSort, Operation::Write(position)) => self.fix(position, operation, items),
+            _ => {}
+        }
+
+        match operation {
+            Operation::Swap(i_index, j_index) => {
+                self.check_fixed(i_index, operation, items);
+                self.check_fixed(j_index, operation, items);
+            }
+            Operation::Write(index)       => self.check_fixed(index, operation, items),
+            Operation::Reverse(low, high) => {
+                for index in low..high {
+                    self.check_fixed(index, operation, items);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn finish(&mut self, items: &[T]) {
+        if self.violation.is_none() {
+            self.checks += 1;
+            if let Some(index) = (0..items.len()).find(|index| items[*index].compare(&self.sorted[*index]) != Ordering::Equal) {
+                self.violation = Some(format!("megsertett utofeltetel \"a vegeredmeny a bemenet rendezett permutacioja\": a(z) {}. elem {}, de {} kellene legyen",
+                                              index, items[index].label(), self.sorted[index].label()));
+            }
+        }
+    }
+
+    fn shrink_active_range(&mut self, low: usize, high: usize, operation: Operation, items: &[T]) {
+        let (previous_low, previous_high) = self.active_range;
+        for index in (previous_low..low.max(previous_low)).chain(high.min(previous_high)..previous_high) {
+            self.fix(index, operation, items);
+        }
+        self.active_range = (low.max(previous_low), high.min(previous_high));
+    }
+
+    fn fix(&mut self, index: usize, operation: Operation, items: &[T]) {
+        self.fixed[index] = true;
+        self.check_fixed(index, operation, items);
+    }
+
+    fn check_fixed(&mut self, index: usize, operation: Operation, items: &[T]) {
+        if self.violation.is_none() && self.fixed[index] {
+            self.checks += 1;
+            if items[index].compare(&self.sorted[index]) != Ordering::Equal {
+                self.violate(format!("a(z) {}. pozicio erteke {}, de a vegleges erteke {} lenne", index, items[index].label(), self.sorted[index].label()), operation);
+            }
+        }
+    }
+
+    fn check_neighbours(&mut self, index: usize, end: usize, operation: Operation, items: &[T]) {
+        self.checks += 1;
+        let before = index.checked_sub(1).map(|previous| items[previous].compare(&items[index]) != Ordering::Greater);
+        let after  = (index + 1 < end).then(|| items[index].compare(&items[index + 1]) != Ordering::Greater);
+        if before == Some(false) || after == Some(false) {
+            self.violate(format!("a(z) {}. pozicioba beszurt elem ({}) nem a helyere kerult", index, items[index].label()), operation);
+        }
+    }
+
+    fn check_sorted_except(&mut self, end: usize, skipped: usize, operation: Operation, items: &[T]) {
+        self.checks += 1;
+        let prefix: Vec<usize> = (0..end).filter(|index| *index != skipped)
+                                         .collect();
+        if prefix.windows(2).any(|pair| items[pair[0]].compare(&items[pair[1]]) == Ordering::Greater) {
+            self.violate(format!("a [0, {}] prefix a beszurando elemtol eltekintve nem rendezett", end - 1), operation);
+        }
+    }
+
+    fn check_sorted_run(&mut self, low: usize, high: usize, operation: Operation, items: &[T]) {
+        self.checks += 1;
+        if let Some(index) = (low + 1..high).find(|index| items[index - 1].compare(&items[*index]) == Ordering::Greater) {
+            self.violate(format!("a [{}, {}) futam nem rendezett a(z) {}. pozicional", low, high, index), operation);
+        }
+    }
+
+    fn violate(&mut self, message: String, operation: Operation) {
+        if self.violation.is_none() {
+            self.violation = Some(format!("megsertett invarians \"{}\": {} (muvelet: {:?})", self.description(), message, operation));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+    use super::*;
+    use crate::{ signed_data, sort_algorithms::seeded_rng, sort_array::SortArray };
+
+    fn checked_run<T: SortValue>(algorithm: Algorithm, values: Vec<T>) -> InvariantChecker<T> {
+        let mut invariants = InvariantChecker::new(algorithm, &values);
+        let mut array      = SortArray::new(values, T::compare, seeded_rng(3))
+                                      .with_observer(|operation, items| invariants.update(operation, items));
+        algorithm.sort(&mut array);
+        let sorted_values = array.into_items();
+
+        invariants.finish(&sorted_values);
+        invariants
+    }
+
+    #[test]
+    fn correct_runs_keep_every_invariant() {
+        let mut rng = seeded_rng(5);
+        for algorithm in Algorithm::ALL {
+            let numbers: Vec<u64> = (0..60).map(|_| rng.gen_range(0..30)).collect();
+            let invariants        = checked_run(algorithm, numbers);
+            assert_eq!(invariants.violation(), None, "{}", algorithm.name());
+            assert!(invariants.checks() > 0, "{}", algorithm.name());
+
+            let floats = checked_run(algorithm, signed_data::generate_float_values(60, 5));
+            assert_eq!(floats.violation(), None, "{}", algorithm.name());
+        }
+    }
+
+    #[test]
+    fn misplaced_pivot_is_reported() {
+        let items          = [3u64, 1, 2];
+        let mut invariants = InvariantChecker::new(Algorithm::QuickSort, &items);
+        invariants.update(Operation::PivotPlaced(0), &items);
+        assert!(invariants.violation().is_some());
+
+        invariants.update(Operation::PivotPlaced(1), &items);
+        assert_eq!(invariants.checks(), 1);
+    }
+
+    #[test]
+    fn swap_out_of_a_final_suffix_is_reported() {
+        let mut items      = [1u64, 2, 3];
+        let mut invariants = InvariantChecker::new(Algorithm::BubbleSort, &items);
+        invariants.update(Operation::ActiveRange(0, 2), &items);
+        assert_eq!(invariants.violation(), None);
+
+        items.swap(1, 2);
+        invariants.update(Operation::Swap(1, 2), &items);
+        assert!(invariants.violation().is_some());
+    }
+
+    #[test]
+    fn unsorted_result_is_reported() {
+        let mut invariants = InvariantChecker::new(Algorithm::GnomeSort, &[2.5, -1.0]);
+        invariants.finish(&[2.5, -1.0]);
+        assert!(invariants.violation().is_some());
+        assert_eq!(invariants.checks(), 1);
+    }
+}
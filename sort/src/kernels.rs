@@ -0,0 +1,167 @@
+pub const NETWORK_BLOCK_SIZE: usize = 16;
+
+const NETWORK_4: [(usize, usize); 5]   = batcher_network::<4, 5>();
+const NETWORK_8: [(usize, usize); 19]  = batcher_network::<8, 19>();
+const NETWORK_16: [(usize, usize); 63] = batcher_network::<16, 63>();
+
+const fn batcher_network<const N: usize, const C: usize>() -> [(usize, usize); C] {
+    let mut pairs = [(0, 0); C];
+    let mut count = 0;
+
+    let mut p = 1;
+    while p < N {
+        let mut k = p;
+        while k >= 1 {
+            let mut j = k % p;
+            while j + k < N {
+                let mut i = 0;
+                while i < k && i + j + k < N {
+                    if (i + j) / (2 * p) == (i + j + k) / (2 * p) {
+                        pairs[count] = (i + j, i + j + k);
+                        count += 1;
+                    }
+                    i += 1;
+                }
+                j += 2 * k;
+            }
+            k /= 2;
+        }
+        p *= 2;
+    }
+
+    assert!(count == C);
+    pairs
+}
+
+pub fn insertion_sort(numbers: &mut [u64]) {
+    for i_index in 1..numbers.len() {
+        let mut j_index = i_index;
+        while j_index > 0 && numbers[j_index - 1] > numbers[j_index] {
+            numbers.swap(j_index - 1, j_index);
+            j_index -= 1;
+        }
+    }
+}
+
+pub fn branchless_insertion_sort(numbers: &mut [u64]) {
+    for i_index in 1..numbers.len() {
+        let value    = numbers[i_index];
+        let mut base = 0;
+        let mut size = i_index;
+        while size > 1 {
+            let half = size / 2;
+            base     = if numbers[base + half] <= value { base + half } else { base };
+            size    -= half;
+        }
+        let position = base + (numbers[base] <= value) as usize;
+
+        numbers.copy_within(position..i_index, position + 1);
+        numbers[position] = value;
+    }
+}
+
+fn apply_network<const N: usize>(block: &mut [u64; N], pairs: &[(usize, usize)]) {
+    for &(low, high) in pairs {
+        let (first, second) = (block[low], block[high]);
+        block[low]  = first.min(second);
+        block[high] = first.max(second);
+    }
+}
+
+fn network_sort_block<const N: usize>(numbers: &mut [u64], pairs: &[(usize, usize)]) {
+    let mut block = [u64::MAX; N];
+    block[..numbers.len()].copy_from_slice(numbers);
+    apply_network(&mut block, pairs);
+    numbers.copy_from_slice(&block[..numbers.len()]);
+}
+
+pub fn network_sort(numbers: &mut [u64]) {
+    match numbers.len() {
+        0..=1  => {}
+        2..=4  => network_sort_block::<4>(numbers, &NETWORK_4),
+        5..=8  => network_sort_block::<8>(numbers, &NETWORK_8),
+        9..=16 => network_sort_block::<16>(numbers, &NETWORK_16),
+        _      => {
+            for block in numbers.chunks_mut(NETWORK_BLOCK_SIZE) {
+                network_sort(block);
+            }
+            merge_blocks(numbers, NETWORK_BLOCK_SIZE);
+        }
+    }
+}
+
+fn merge_blocks(numbers: &mut [u64], block_size: usize) {
+    let mut buffer = numbers.to_vec();
+    let mut width  = block_size;
+
+    while width < numbers.len() {
+        for low in (0..numbers.len()).step_by(2 * width) {
+            let middle = (low + width).min(numbers.len());
+            let high   = (low + 2 * width).min(numbers.len());
+            let (mut left, mut right) = (low, middle);
+
+            for slot in &mut buffer[low..high] {
+                let take_left = right >= high || (left < middle && numbers[left] <= numbers[right]);
+                *slot = if take_left { numbers[left] } else { numbers[right] };
+                left  += take_left as usize;
+                right += !take_left as usize;
+            }
+        }
+        numbers.copy_from_slice(&buffer);
+        width *= 2;
+    }
+}
+
+fn lomuto_partition(numbers: &mut [u64]) -> usize {
+    let last_index      = numbers.len() - 1;
+    let mut store_index = 0;
+
+    for j_index in 0..last_index {
+        if numbers[j_index] < numbers[last_index] {
+            numbers.swap(store_index, j_index);
+            store_index += 1;
+        }
+    }
+    numbers.swap(store_index, last_index);
+    store_index
+}
+
+fn branchless_lomuto_partition(numbers: &mut [u64]) -> usize {
+    let last_index      = numbers.len() - 1;
+    let pivot           = numbers[last_index];
+    let mut store_index = 0;
+
+    for j_index in 0..last_index {
+        let value            = numbers[j_index];
+        let displaced        = numbers[store_index];
+        numbers[store_index] = value;
+        numbers[j_index]     = displaced;
+        store_index         += (value < pivot) as usize;
+    }
+    numbers.swap(store_index, last_index);
+    store_index
+}
+
+fn lomuto_quick_sort(mut numbers: &mut [u64], partition: fn(&mut [u64]) -> usize) {
+    while numbers.len() > 1 {
+        let pivot_index   = partition(numbers);
+        let (left, right) = numbers.split_at_mut(pivot_index);
+        let right         = &mut right[1..];
+
+        if left.len() < right.len() {
+            lomuto_quick_sort(left, partition);
+            numbers = right;
+        } else {
+            lomuto_quick_sort(right, partition);
+            numbers = left;
+        }
+    }
+}
+
+pub fn quick_sort(numbers: &mut [u64]) {
+    lomuto_quick_sort(numbers, lomuto_partition);
+}
+
+pub fn branchless_quick_sort(numbers: &mut [u64]) {
+    lomuto_quick_sort(numbers, branchless_lomuto_partition);
+}
@@ -1,229 +1,846 @@
+pub mod adversary;
+pub mod array_editor;
+pub mod benchmark;
+pub mod chart_view;
+pub mod command_line;
+pub mod cost_model;
+pub mod history;
+pub mod input_data;
+pub mod invariants;
+pub mod kernels;
+pub mod linked_sort;
+pub mod narration;
+pub mod prompt;
+pub mod report;
+pub mod search_tree;
+pub mod signed_data;
+pub mod sonification;
+pub mod sort_array;
+pub mod sort_value;
+pub mod sortedness;
+pub mod sorting_game;
+pub mod string_sort;
+pub mod tree_view;
+
 pub mod sort_algorithms {
 
-    use std::{ time, thread };
-    use tui::{ widgets::{ Block, BarChart, BorderType, Borders, Paragraph },
+    use std::{ cmp::Ordering, time };
+    use crossterm::event::{ self, Event, KeyCode };
+    use tui::{ widgets::{ Block, BorderType, Borders, Gauge, Paragraph },
                backend::Backend,
-               layout::{ Layout, Direction, Constraint, Alignment },
+               layout::{ Layout, Direction, Constraint, Alignment, Rect },
                style::{ Style, Color, Modifier },
-               text::Span,
+               text::{ Span, Spans },
                Frame,
                Terminal };
-    use rand::Rng;
+    use rand::{ Rng, SeedableRng, rngs::StdRng };
+    use crate::{ chart_view::{ self, ChartView },
+                 invariants::InvariantChecker,
+                 narration::{ Narrator, NARRATION_HEIGHT },
+                 search_tree::SearchTree,
+                 sort_array::{ Counters, Operation, SortArray },
+                 sort_value::{ ChartValue, SortValue },
+                 sortedness::Sortedness,
+                 tree_view::TreeView };
+
+    pub const MAX_VECTOR_SIZE: usize   = 39;
+    pub const MAX_ARRAY_SIZE: usize    = 10000;
+    pub const MAX_RANDOM_NUMBER: u64   = 10000;
+    const INCREMENTAL_SORTEDNESS_LIMIT: usize   = 1000;
+    pub const FRAME_INTERVAL_MILLIS: u64        = 33;
+    const INTRO_SORT_INSERTION_THRESHOLD: usize = 16;
+
+    pub fn seeded_rng(seed: u64) -> StdRng {
+        StdRng::seed_from_u64(seed)
+    }
 
-    const MAX_VECTOR_SIZE: i32 = 39;
+    pub fn random_seed() -> u64 {
+        rand::thread_rng().gen()
+    }
 
-    fn fill_vector_with_random_numbers(numbers: &mut Vec<u64>) {
+    pub fn fill_vector_with_random_numbers(numbers: &mut Vec<u64>, size: usize, rng: &mut StdRng) {
         let mut _random_number: u64 = 0;
-    
-        for _index in 0..MAX_VECTOR_SIZE {
-            _random_number = rand::thread_rng()
-                                  .gen_range(1..10000);
+
+        for _index in 0..size {
+            _random_number = rng.gen_range(1..MAX_RANDOM_NUMBER);
             numbers.push(_random_number);
         }
     }
-    
-    fn convert_vector_to_tuple_vector(numbers: &Vec<u64>) -> Vec<(&str, u64)> {
-        let mut numbers_with_tuple: Vec<(&str, u64)> = Vec::new();
-    
-        for number in numbers {
-            numbers_with_tuple.push(("", *number));
+
+    pub fn prepare_numbers(numbers: &mut Vec<u64>, input_numbers: &[u64], size: usize, seed: u64) {
+        numbers.clear();
+
+        if input_numbers.is_empty() {
+            fill_vector_with_random_numbers(numbers, size, &mut seeded_rng(seed));
+        } else {
+            numbers.extend_from_slice(input_numbers);
         }
+    }
     
-        numbers_with_tuple
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Algorithm {
+        SimpleSort,
+        MinSort,
+        BubbleSort,
+        OptBubbleSort,
+        InsertSort,
+        GnomeSort,
+        CocktailSort,
+        CombSort,
+        OddEvenSort,
+        CycleSort,
+        PancakeSort,
+        HeapSort,
+        TreeSort,
+        MergeSort,
+        QuickSort,
+        MedianQuickSort,
+        RandomQuickSort,
+        IntroSort
     }
 
-    fn swap(numbers: &mut Vec<u64>, i_index: usize, j_index: usize) {
-        let value        = numbers[i_index];
-        numbers[i_index] = numbers[j_index];
-        numbers[j_index] = value;
+    impl Algorithm {
+        pub const ALL: [Algorithm; 18] = [
+            Algorithm::SimpleSort,
+            Algorithm::MinSort,
+            Algorithm::BubbleSort,
+            Algorithm::OptBubbleSort,
+            Algorithm::InsertSort,
+            Algorithm::GnomeSort,
+            Algorithm::CocktailSort,
+            Algorithm::CombSort,
+            Algorithm::OddEvenSort,
+            Algorithm::CycleSort,
+            Algorithm::PancakeSort,
+            Algorithm::HeapSort,
+            Algorithm::TreeSort,
+            Algorithm::MergeSort,
+            Algorithm::QuickSort,
+            Algorithm::MedianQuickSort,
+            Algorithm::RandomQuickSort,
+            Algorithm::IntroSort
+        ];
+
+        pub fn name(self) -> &'static str {
+            match self {
+                Algorithm::SimpleSort      => "Egyszeru cseres rendezes",
+                Algorithm::MinSort         => "Minimum kivalasztasos rendezes",
+                Algorithm::BubbleSort      => "Buborekos rendezes",
+                Algorithm::OptBubbleSort   => "Javitott buborekos rendezes",
+                Algorithm::InsertSort      => "Beilleszteses rendezes",
+                Algorithm::GnomeSort       => "Gnome rendezes",
+                Algorithm::CocktailSort    => "Koktelrazo rendezes",
+                Algorithm::CombSort        => "Fesus rendezes",
+                Algorithm::OddEvenSort     => "Paros-paratlan rendezes",
+                Algorithm::CycleSort       => "Ciklusrendezes",
+                Algorithm::PancakeSort     => "Palacsintarendezes",
+                Algorithm::HeapSort        => "Kupacrendezes",
+                Algorithm::TreeSort        => "Faszerkezetes rendezes (binaris keresofa)",
+                Algorithm::MergeSort       => "Osszefesuleses rendezes",
+                Algorithm::QuickSort       => "Gyorsrendezes (utolso elem pivot)",
+                Algorithm::MedianQuickSort => "Gyorsrendezes (harmas median pivot)",
+                Algorithm::RandomQuickSort => "Gyorsrendezes (veletlen pivot)",
+                Algorithm::IntroSort       => "Introsort"
+            }
+        }
+
+        pub fn key(self) -> &'static str {
+            match self {
+                Algorithm::SimpleSort      => "simple",
+                Algorithm::MinSort         => "min",
+                Algorithm::BubbleSort      => "bubble",
+                Algorithm::OptBubbleSort   => "opt_bubble",
+                Algorithm::InsertSort      => "insert",
+                Algorithm::GnomeSort       => "gnome",
+                Algorithm::CocktailSort    => "cocktail",
+                Algorithm::CombSort        => "comb",
+                Algorithm::OddEvenSort     => "odd_even",
+                Algorithm::CycleSort       => "cycle",
+                Algorithm::PancakeSort     => "pancake",
+                Algorithm::HeapSort        => "heap",
+                Algorithm::TreeSort        => "tree",
+                Algorithm::MergeSort       => "merge",
+                Algorithm::QuickSort       => "quick",
+                Algorithm::MedianQuickSort => "median_quick",
+                Algorithm::RandomQuickSort => "random_quick",
+                Algorithm::IntroSort       => "intro"
+            }
+        }
+
+        pub fn from_key(key: &str) -> Option<Algorithm> {
+            Algorithm::ALL.iter()
+                          .copied()
+                          .find(|algorithm| algorithm.key() == key)
+        }
+
+        pub fn is_in_place(self) -> bool {
+            match self {
+                Algorithm::SimpleSort | Algorithm::MinSort | Algorithm::BubbleSort | Algorithm::OptBubbleSort |
+                Algorithm::InsertSort | Algorithm::GnomeSort | Algorithm::CocktailSort | Algorithm::CombSort |
+                Algorithm::OddEvenSort | Algorithm::CycleSort | Algorithm::PancakeSort | Algorithm::HeapSort |
+                Algorithm::QuickSort | Algorithm::MedianQuickSort | Algorithm::RandomQuickSort | Algorithm::IntroSort => true,
+                Algorithm::TreeSort | Algorithm::MergeSort => false
+            }
+        }
+
+        pub fn has_tree_view(self) -> bool {
+            matches!(self, Algorithm::HeapSort | Algorithm::TreeSort)
+        }
+
+        pub fn is_quick_sort_variant(self) -> bool {
+            matches!(self, Algorithm::QuickSort | Algorithm::MedianQuickSort | Algorithm::RandomQuickSort | Algorithm::IntroSort)
+        }
+
+        pub fn sort<T: Clone>(self, array: &mut SortArray<T>) {
+            match self {
+                Algorithm::SimpleSort      => simple_sort(array),
+                Algorithm::MinSort         => min_sort(array),
+                Algorithm::BubbleSort      => bubble_sort(array),
+                Algorithm::OptBubbleSort   => opt_bubble_sort(array),
+                Algorithm::InsertSort      => insert_sort(array),
+                Algorithm::GnomeSort       => gnome_sort(array),
+                Algorithm::CocktailSort    => cocktail_sort(array),
+                Algorithm::CombSort        => comb_sort(array),
+                Algorithm::OddEvenSort     => odd_even_sort(array),
+                Algorithm::CycleSort       => cycle_sort(array),
+                Algorithm::PancakeSort     => pancake_sort(array),
+                Algorithm::HeapSort        => heap_sort(array),
+                Algorithm::TreeSort        => tree_sort(array),
+                Algorithm::MergeSort       => merge_sort(array),
+                Algorithm::QuickSort       => quick_sort(array),
+                Algorithm::MedianQuickSort => median_quick_sort(array),
+                Algorithm::RandomQuickSort => random_quick_sort(array),
+                Algorithm::IntroSort       => intro_sort(array)
+            }
+        }
     }
 
-    fn clear_chart_screen<B: Backend>(numbers: &mut Vec<u64>, terminal: &mut Terminal<B>) {
-        numbers.clear();
-        terminal.draw(|frame| chart_screen(frame, numbers, "", String::from(""))).ok();
+    struct ChartPanels<'a, T> {
+        sortedness: Option<&'a Sortedness<T>>,
+        tree: Option<&'a TreeView<T>>,
+        invariants: Option<&'a InvariantChecker<T>>,
+        narration: Option<&'a Narrator<T>>
     }
 
-    fn draw_elapsed_time<B: Backend>(numbers: &mut Vec<u64>, terminal: &mut Terminal<B>, title: &str, elapsed_time_millis: String) {
-        let elapsed_time = String::from("A rendezési algoritmus végrehajtási ideje: ") + &elapsed_time_millis + &String::from(" ms");
-    
-        terminal.draw(|frame| chart_screen(frame, numbers, title, elapsed_time)).ok();
-        thread::sleep(time::Duration::from_millis(5000));
+    impl<'a, T> Clone for ChartPanels<'a, T> {
+        fn clone(&self) -> ChartPanels<'a, T> {
+            *self
+        }
     }
-    
-    pub fn simple_sort<B: Backend>(numbers: &mut Vec<u64>, terminal: &mut Terminal<B>) {
-        fill_vector_with_random_numbers(numbers);
+
+    impl<'a, T> Copy for ChartPanels<'a, T> {}
+
+    impl<'a, T> Default for ChartPanels<'a, T> {
+        fn default() -> ChartPanels<'a, T> {
+            ChartPanels { sortedness: None, tree: None, invariants: None, narration: None }
+        }
+    }
+
+    pub fn run_sort_algorithm<B: Backend, T: ChartValue>(algorithm: Algorithm, numbers: &mut Vec<T>, terminal: &mut Terminal<B>, seed: u64, input_label: &str, view: &mut ChartView) -> (Counters, u128) {
+        let title  = match T::ORDER {
+            Some(order) => format!("{} | sorrend: {}", algorithm.name(), order),
+            None        => algorithm.name().to_string()
+        };
+        let header = format!("Vegrehajtasi-ido eredmenyablak | Seed: {} | Bemenet: {}", seed, input_label);
+
+        let mut sortedness = Sortedness::new(numbers);
+        let mut tree_view  = TreeView::new(algorithm);
+        let mut invariants = InvariantChecker::new(algorithm, numbers);
+        let mut narrator   = Narrator::new();
+        let incremental    = numbers.len() <= INCREMENTAL_SORTEDNESS_LIMIT;
+        let animate_all    = numbers.len() <= MAX_VECTOR_SIZE;
+        let mut last_frame = time::Instant::now();
 
         let current_time = time::Instant::now();
+
+        let mut array = SortArray::new(numbers.clone(), T::compare, seeded_rng(seed))
+                                  .with_observer(|operation, items| {
+                                      if incremental {
+                                          sortedness.update(operation, items);
+                                      }
+                                      if let Some(tree_view) = tree_view.as_mut() {
+                                          tree_view.update(operation, items);
+                                      }
+                                      invariants.update(operation, items);
+                                      narrator.update(operation, items);
+                                      if (operation.changes_items() || operation.is_marker()) && (animate_all || last_frame.elapsed().as_millis() as u64 >= FRAME_INTERVAL_MILLIS) {
+                                          if !incremental {
+                                              sortedness.refresh(items);
+                                          }
+                                          poll_chart_keys(view, &mut narrator);
+                                          let panels = ChartPanels { sortedness: Some(&sortedness), tree: tree_view.as_ref(), invariants: Some(&invariants), narration: Some(&narrator) };
+                                          terminal.draw(|frame| chart_screen(frame, items, &title, String::new(), &header, panels, *view)).ok();
+                                          last_frame = time::Instant::now();
+                                      }
+                                  });
+        algorithm.sort(&mut array);
+
+        let elapsed_time_millis = current_time.elapsed().as_millis();
+        let counters            = array.counters();
+        *numbers                = array.into_items();
+
+        sortedness.refresh(numbers);
+        invariants.finish(numbers);
+        let elapsed_time = elapsed_time_label(elapsed_time_millis, counters);
+        loop {
+            let panels = ChartPanels { sortedness: Some(&sortedness), tree: tree_view.as_ref(), invariants: Some(&invariants), narration: Some(&narrator) };
+            terminal.draw(|frame| chart_screen(frame, numbers, &title, elapsed_time.clone(), &header, panels, *view)).ok();
+
+            match event::read() {
+                Ok(Event::Key(key)) => match key.code {
+                    KeyCode::Char('v') | KeyCode::Char('V') => *view = view.next(),
+                    code => if !narrator.handle_key(code, algorithm.name(), algorithm.key(), numbers.len(), seed) {
+                        break;
+                    }
+                },
+                Ok(_)  => {}
+                Err(_) => break
+            }
+        }
     
-        for i_index in 0..numbers.len() - 1 {
-            for j_index in i_index + 1..numbers.len() {
-                if numbers[i_index] > numbers[j_index] {
-                    swap(numbers, i_index, j_index);
-                    terminal.draw(|frame| chart_screen(frame, numbers, "Egyszeru cseres rendezes", String::from(""))).ok();
+        clear_chart_screen(numbers, terminal, *view);
+
+        (counters, elapsed_time_millis)
+    }
+
+    fn poll_chart_keys<T: SortValue>(view: &mut ChartView, narrator: &mut Narrator<T>) {
+        while event::poll(time::Duration::ZERO).unwrap_or(false) {
+            if let Ok(Event::Key(key)) = event::read() {
+                match key.code {
+                    KeyCode::Char('v') | KeyCode::Char('V') => *view = view.next(),
+                    KeyCode::Char('f') | KeyCode::Char('F') => narrator.next_filter(),
+                    _ => {}
                 }
             }
         }
-        
-        let elapsed_time_millis = current_time.elapsed().as_millis().to_string();
-        draw_elapsed_time(numbers, terminal, "Egyszeru cseres rendezes", elapsed_time_millis);
-    
-        clear_chart_screen(numbers, terminal);
     }
-    
-    pub fn min_sort<B: Backend>(numbers: &mut Vec<u64>, terminal: &mut Terminal<B>) {
-        fill_vector_with_random_numbers(numbers);
 
-        let current_time = time::Instant::now();
+    fn clear_chart_screen<B: Backend, T: ChartValue>(numbers: &mut Vec<T>, terminal: &mut Terminal<B>, view: ChartView) {
+        numbers.clear();
+        terminal.draw(|frame| chart_screen(frame, numbers, "", String::new(), "", ChartPanels::default(), view)).ok();
+    }
+
+    fn elapsed_time_label(elapsed_time_millis: u128, counters: Counters) -> String {
+        let mut elapsed_time = format!("A rendezési algoritmus végrehajtási ideje: {} ms | Osszehasonlitasok: {} | Cserek: {}",
+                                       elapsed_time_millis, counters.comparisons, counters.swaps);
+        if counters.writes > 0 {
+            elapsed_time += &format!(" | Irasok: {}", counters.writes);
+        }
+        if counters.reversals > 0 {
+            elapsed_time += &format!(" | Forditasok (flip): {}", counters.reversals);
+        }
+
+        elapsed_time + " | Narracio: Fel/Le/PgUp/PgDn gorgetes, F szuro, E mentes | Tovabb: <barmely mas billentyu>"
+    }
+
+    pub fn wait_for_key() {
+        while !matches!(event::read(), Ok(Event::Key(_)) | Err(_)) {}
+    }
+
+    
+    pub fn simple_sort<T>(array: &mut SortArray<T>) {
+        for i_index in 0..array.len().saturating_sub(1) {
+            array.mark(Operation::ActiveRange(i_index, array.len()));
+            for j_index in i_index + 1..array.len() {
+                if array.greater(i_index, j_index) {
+                    array.swap(i_index, j_index);
+                }
+            }
+        }
+    }
     
+    pub fn min_sort<T>(array: &mut SortArray<T>) {
         let mut _min_value_index: usize  = 0;
     
-        for i_index in 0..numbers.len() - 1 {
+        for i_index in 0..array.len().saturating_sub(1) {
+            array.mark(Operation::ActiveRange(i_index, array.len()));
             _min_value_index = i_index;
-            for j_index in i_index + 1..numbers.len() {
-                if numbers[_min_value_index] > numbers[j_index] {
+            for j_index in i_index + 1..array.len() {
+                if array.greater(_min_value_index, j_index) {
                     _min_value_index = j_index;
                 }
             }
-            swap(numbers, i_index, _min_value_index);
-            terminal.draw(|frame| chart_screen(frame, numbers, "Minimum kivalasztasos rendezes", String::from(""))).ok();
+            array.swap(i_index, _min_value_index);
         }
-    
-        let elapsed_time_millis = current_time.elapsed().as_millis().to_string();
-        draw_elapsed_time(numbers, terminal, "Minimum kivalasztasos rendezes", elapsed_time_millis);
-    
-        clear_chart_screen(numbers, terminal);
     }
     
-    pub fn bubble_sort<B: Backend>(numbers: &mut Vec<u64>, terminal: &mut Terminal<B>) {
-        fill_vector_with_random_numbers(numbers);
-
-        let current_time = time::Instant::now();
-    
-        for i_index in (1..numbers.len()).rev() {
+    pub fn bubble_sort<T>(array: &mut SortArray<T>) {
+        for i_index in (1..array.len()).rev() {
+            array.mark(Operation::ActiveRange(0, i_index + 1));
             for j_index in 0..i_index {
-                if numbers[j_index] > numbers[j_index + 1] {
-                    swap(numbers, j_index, j_index + 1);
-                    terminal.draw(|frame| chart_screen(frame, numbers, "Buborekos rendezes", String::from(""))).ok();
+                if array.greater(j_index, j_index + 1) {
+                    array.swap(j_index, j_index + 1);
                 }
             }
         }
-    
-        let elapsed_time_millis = current_time.elapsed().as_millis().to_string();
-        draw_elapsed_time(numbers, terminal, "Buborekos rendezes", elapsed_time_millis);
-    
-        clear_chart_screen(numbers, terminal);
     }
     
-    pub fn opt_bubble_sort<B: Backend>(numbers: &mut Vec<u64>, terminal: &mut Terminal<B>) { 
-        fill_vector_with_random_numbers(numbers);
-
-        let current_time = time::Instant::now();
-        
+    pub fn opt_bubble_sort<T>(array: &mut SortArray<T>) { 
         let mut _last_swap_index = 0;
-        let mut i_index = numbers.len() - 1;
+        let mut i_index = array.len().saturating_sub(1);
         while i_index >= 1 {
+            array.mark(Operation::ActiveRange(0, i_index + 1));
             _last_swap_index = 0;
             for j_index in 0..i_index {
-                if numbers[j_index] > numbers[j_index + 1] {
-                    swap(numbers, j_index, j_index + 1);
+                if array.greater(j_index, j_index + 1) {
+                    array.swap(j_index, j_index + 1);
                     _last_swap_index = j_index;
-                    terminal.draw(|frame| chart_screen(frame, numbers, "Javitott buborekos rendezes", String::from(""))).ok();
                 }
             }
             i_index = _last_swap_index;
         }
-    
-        let elapsed_time_millis = current_time.elapsed().as_millis().to_string();
-        draw_elapsed_time(numbers, terminal, "Javitott buborekos rendezes", elapsed_time_millis);
-    
-        clear_chart_screen(numbers, terminal);
     }
     
-    pub fn insert_sort<B: Backend>(numbers: &mut Vec<u64>, terminal: &mut Terminal<B>) {
-        fill_vector_with_random_numbers(numbers);
+    pub fn insert_sort<T>(array: &mut SortArray<T>) {
+        insert_sort_range(array, 0, array.len());
+    }
 
-        let current_time = time::Instant::now();
-    
-        for i_index in 1..numbers.len() {
-            let mut j_index = i_index - 1;
-            while (j_index as i32) >= 0 && numbers[j_index] > numbers[j_index + 1] {
-                swap(numbers, j_index, j_index + 1);
-                terminal.draw(|frame| chart_screen(frame, numbers, "Beilleszteses rendezes", String::from(""))).ok();
+    fn insert_sort_range<T>(array: &mut SortArray<T>, low: usize, high: usize) {
+        for i_index in low + 1..high {
+            array.mark(Operation::Inserting(i_index));
+            let mut j_index = i_index;
+            while j_index > low && array.greater(j_index - 1, j_index) {
+                array.swap(j_index - 1, j_index);
                 j_index -= 1;
             }
         }
-    
-        let elapsed_time_millis = current_time.elapsed().as_millis().to_string();
-        draw_elapsed_time(numbers, terminal, "Beilleszteses rendezes", elapsed_time_millis);
-    
-        clear_chart_screen(numbers, terminal);
     }
     
-    pub fn gnome_sort<B: Backend>(numbers: &mut Vec<u64>, terminal: &mut Terminal<B>) {
-        fill_vector_with_random_numbers(numbers);
-
-        let current_time = time::Instant::now();
-    
+    pub fn gnome_sort<T>(array: &mut SortArray<T>) {
         let mut index = 0;
-        while index < numbers.len() {
+        while index < array.len() {
             if index == 0 {
                 index += 1;
             }
     
-            if numbers[index] >= numbers[index - 1] {
+            if index >= array.len() || !array.less(index, index - 1) {
                 index += 1;
             } else {
-                swap(numbers, index, index - 1);
-                terminal.draw(|frame| chart_screen(frame, numbers, "Gnome rendezes", String::from(""))).ok();
+                array.swap(index, index - 1);
                 index -= 1;
             }
         }
-    
-        let elapsed_time_millis = current_time.elapsed().as_millis().to_string();
-        draw_elapsed_time(numbers, terminal, "Gnome rendezes", elapsed_time_millis);
-    
-        clear_chart_screen(numbers, terminal);
     }
 
-    fn chart_screen<B: Backend>(frame: &mut Frame<B>, numbers: &Vec<u64>, title: &str, label_elapsed_time: String) {
+    pub fn cocktail_sort<T>(array: &mut SortArray<T>) {
+        let mut low  = 0;
+        let mut high = array.len().saturating_sub(1);
+
+        while low < high {
+            array.mark(Operation::ActiveRange(low, high + 1));
+            let mut _last_forward_swap_index = low;
+            for j_index in low..high {
+                if array.greater(j_index, j_index + 1) {
+                    array.swap(j_index, j_index + 1);
+                    _last_forward_swap_index = j_index;
+                }
+            }
+            high = _last_forward_swap_index;
+
+            let mut _last_backward_swap_index = high;
+            for j_index in (low..high).rev() {
+                if array.greater(j_index, j_index + 1) {
+                    array.swap(j_index, j_index + 1);
+                    _last_backward_swap_index = j_index + 1;
+                }
+            }
+            low = _last_backward_swap_index;
+        }
+    }
+
+    pub fn comb_sort<T>(array: &mut SortArray<T>) {
+        let mut gap     = array.len();
+        let mut swapped = true;
+
+        while gap > 1 || swapped {
+            gap     = (gap * 10 / 13).max(1);
+            swapped = false;
+
+            for i_index in 0..array.len().saturating_sub(gap) {
+                if array.greater(i_index, i_index + gap) {
+                    array.swap(i_index, i_index + gap);
+                    swapped = true;
+                }
+            }
+        }
+    }
+
+    pub fn odd_even_sort<T>(array: &mut SortArray<T>) {
+        let mut sorted = false;
+
+        while !sorted {
+            sorted = true;
+            for start_index in [1, 0] {
+                for i_index in (start_index..array.len().saturating_sub(1)).step_by(2) {
+                    if array.greater(i_index, i_index + 1) {
+                        array.swap(i_index, i_index + 1);
+                        sorted = false;
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn cycle_sort<T: Clone>(array: &mut SortArray<T>) {
+        for cycle_start in 0..array.len().saturating_sub(1) {
+            let mut item     = array.read(cycle_start);
+            let mut position = cycle_position(array, cycle_start, &item);
+
+            if position == cycle_start {
+                continue;
+            }
+
+            while array.compare_value(position, &item) == Ordering::Equal {
+                position += 1;
+            }
+            item = array.replace(position, item);
+
+            while position != cycle_start {
+                position = cycle_position(array, cycle_start, &item);
+                while position != cycle_start && array.compare_value(position, &item) == Ordering::Equal {
+                    position += 1;
+                }
+                item = array.replace(position, item);
+            }
+        }
+    }
+
+    fn cycle_position<T>(array: &mut SortArray<T>, cycle_start: usize, item: &T) -> usize {
+        let mut position = cycle_start;
+
+        for i_index in cycle_start + 1..array.len() {
+            if array.compare_value(i_index, item) == Ordering::Less {
+                position += 1;
+            }
+        }
+
+        position
+    }
+
+    pub fn pancake_sort<T>(array: &mut SortArray<T>) {
+        for size in (2..=array.len()).rev() {
+            array.mark(Operation::ActiveRange(0, size));
+            let mut _max_value_index = 0;
+            for i_index in 1..size {
+                if array.greater(i_index, _max_value_index) {
+                    _max_value_index = i_index;
+                }
+            }
+
+            if _max_value_index != size - 1 {
+                if _max_value_index != 0 {
+                    array.reverse(0, _max_value_index + 1);
+                }
+                array.reverse(0, size);
+            }
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    enum PivotRule {
+        Last,
+        MedianOfThree,
+        Random
+    }
+
+    pub fn heap_sort<T>(array: &mut SortArray<T>) {
+        for index in 1..array.len() {
+            array.mark(Operation::SiftUp(index));
+            sift_up(array, index);
+        }
+
+        for end in (1..array.len()).rev() {
+            array.swap(0, end);
+            array.mark(Operation::SiftDown(0, end));
+            sift_down(array, 0, 0, end);
+        }
+    }
+
+    fn sift_up<T>(array: &mut SortArray<T>, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if !array.greater(index, parent) {
+                return;
+            }
+
+            array.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    pub fn tree_sort<T: Clone>(array: &mut SortArray<T>) {
+        let mut tree = SearchTree::with_capacity(array.len());
+
+        for index in 0..array.len() {
+            let link  = tree.find_link(|node_value| array.compare_value(index, node_value));
+            array.mark(Operation::TreeInsert(index, link));
+            let value = array.read(index);
+            tree.attach(value, link);
+        }
+
+        for (position, node) in tree.in_order().into_iter().enumerate() {
+            array.replace(position, tree.nodes()[node].value.clone());
+        }
+    }
+
+    pub fn merge_sort<T: Clone>(array: &mut SortArray<T>) {
+        let mut buffer = Vec::with_capacity(array.len() / 2);
+        merge_sort_range(array, 0, array.len(), &mut buffer);
+    }
+
+    fn merge_sort_range<T: Clone>(array: &mut SortArray<T>, low: usize, high: usize, buffer: &mut Vec<T>) {
+        if high - low < 2 {
+            return;
+        }
+
+        let middle = low + (high - low) / 2;
+        merge_sort_range(array, low, middle, buffer);
+        merge_sort_range(array, middle, high, buffer);
+        array.mark(Operation::Merging(low, middle, high));
+
+        buffer.clear();
+        for index in low..middle {
+            buffer.push(array.read(index));
+        }
+
+        let (mut left, mut right, mut position) = (0, middle, low);
+        while left < buffer.len() && right < high {
+            if array.compare_value(right, &buffer[left]) == Ordering::Less {
+                let value = array.read(right);
+                array.replace(position, value);
+                right += 1;
+            } else {
+                array.replace(position, buffer[left].clone());
+                left += 1;
+            }
+            position += 1;
+        }
+
+        for value in &buffer[left..] {
+            array.replace(position, value.clone());
+            position += 1;
+        }
+    }
+
+    pub fn quick_sort<T>(array: &mut SortArray<T>) {
+        quick_sort_range(array, 0, array.len(), PivotRule::Last);
+    }
+
+    pub fn median_quick_sort<T>(array: &mut SortArray<T>) {
+        quick_sort_range(array, 0, array.len(), PivotRule::MedianOfThree);
+    }
+
+    pub fn random_quick_sort<T>(array: &mut SortArray<T>) {
+        quick_sort_range(array, 0, array.len(), PivotRule::Random);
+    }
+
+    fn quick_sort_range<T>(array: &mut SortArray<T>, mut low: usize, mut high: usize, pivot_rule: PivotRule) {
+        while high - low > 1 {
+            let pivot_index = choose_pivot(array, low, high, pivot_rule);
+            let pivot_index = partition(array, low, high, pivot_index);
+            array.mark(Operation::PivotPlaced(pivot_index));
+
+            if pivot_index - low < high - pivot_index {
+                quick_sort_range(array, low, pivot_index, pivot_rule);
+                low = pivot_index + 1;
+            } else {
+                quick_sort_range(array, pivot_index + 1, high, pivot_rule);
+                high = pivot_index;
+            }
+        }
+    }
+
+    fn choose_pivot<T>(array: &mut SortArray<T>, low: usize, high: usize, pivot_rule: PivotRule) -> usize {
+        match pivot_rule {
+            PivotRule::Last          => high - 1,
+            PivotRule::MedianOfThree => median_of_three(array, low, low + (high - low) / 2, high - 1),
+            PivotRule::Random        => array.rng().gen_range(low..high)
+        }
+    }
+
+    fn median_of_three<T>(array: &mut SortArray<T>, a_index: usize, b_index: usize, c_index: usize) -> usize {
+        if array.less(a_index, b_index) {
+            if array.less(b_index, c_index) {
+                b_index
+            } else if array.less(a_index, c_index) {
+                c_index
+            } else {
+                a_index
+            }
+        } else if array.less(a_index, c_index) {
+            a_index
+        } else if array.less(b_index, c_index) {
+            c_index
+        } else {
+            b_index
+        }
+    }
+
+    fn partition<T>(array: &mut SortArray<T>, low: usize, high: usize, pivot_index: usize) -> usize {
+        let last_index = high - 1;
+        if pivot_index != last_index {
+            array.swap(pivot_index, last_index);
+        }
+
+        let mut store_index = low;
+        for j_index in low..last_index {
+            if array.less(j_index, last_index) {
+                if store_index != j_index {
+                    array.swap(store_index, j_index);
+                }
+                store_index += 1;
+            }
+        }
+
+        if store_index != last_index {
+            array.swap(store_index, last_index);
+        }
+        store_index
+    }
+
+    pub fn intro_sort<T>(array: &mut SortArray<T>) {
+        let depth_limit = 2 * (usize::BITS - array.len().leading_zeros()) as usize;
+        intro_sort_range(array, 0, array.len(), depth_limit);
+    }
+
+    fn intro_sort_range<T>(array: &mut SortArray<T>, mut low: usize, mut high: usize, mut depth_limit: usize) {
+        while high - low > INTRO_SORT_INSERTION_THRESHOLD {
+            if depth_limit == 0 {
+                heap_sort_range(array, low, high);
+                return;
+            }
+            depth_limit -= 1;
+
+            let pivot_index = median_of_three(array, low, low + (high - low) / 2, high - 1);
+            let pivot_index = partition(array, low, high, pivot_index);
+            array.mark(Operation::PivotPlaced(pivot_index));
+
+            if pivot_index - low < high - pivot_index {
+                intro_sort_range(array, low, pivot_index, depth_limit);
+                low = pivot_index + 1;
+            } else {
+                intro_sort_range(array, pivot_index + 1, high, depth_limit);
+                high = pivot_index;
+            }
+        }
+
+        insert_sort_range(array, low, high);
+    }
+
+    fn heap_sort_range<T>(array: &mut SortArray<T>, low: usize, high: usize) {
+        let heap_size = high - low;
+
+        for root in (0..heap_size / 2).rev() {
+            sift_down(array, low, root, heap_size);
+        }
+
+        for end in (1..heap_size).rev() {
+            array.swap(low, low + end);
+            sift_down(array, low, 0, end);
+        }
+    }
+
+    fn sift_down<T>(array: &mut SortArray<T>, offset: usize, mut root: usize, heap_size: usize) {
+        loop {
+            let mut largest = root;
+            let left_child  = 2 * root + 1;
+            let right_child = left_child + 1;
+
+            if left_child < heap_size && array.greater(offset + left_child, offset + largest) {
+                largest = left_child;
+            }
+            if right_child < heap_size && array.greater(offset + right_child, offset + largest) {
+                largest = right_child;
+            }
+            if largest == root {
+                return;
+            }
+
+            array.swap(offset + root, offset + largest);
+            root = largest;
+        }
+    }
+
+    fn chart_screen<B: Backend, T: ChartValue>(frame: &mut Frame<B>, numbers: &[T], title: &str, label_elapsed_time: String, header: &str, panels: ChartPanels<T>, view: ChartView) {
+        let invariant_height = if panels.invariants.is_some() { 3 } else { 0 };
+        let narration_height = if panels.narration.is_some() { NARRATION_HEIGHT } else { 0 };
         let chart_layout = Layout::default()
                                   .direction(Direction::Vertical)
                                   .constraints(
                                       [
-                                          Constraint::Percentage(5),
-                                          Constraint::Percentage(95)
+                                          Constraint::Length(3),
+                                          Constraint::Length(invariant_height),
+                                          Constraint::Min(0),
+                                          Constraint::Length(narration_height)
                                       ]
                                       .as_ref(),
                                   )
                                   .split(frame.size());
+
+        if let Some(invariants) = panels.invariants {
+            invariant_panel(frame, chart_layout[1], invariants);
+        }
+        if let Some(narrator) = panels.narration {
+            narrator.render(frame, chart_layout[3]);
+        }
+
+        let chart_area = match panels.sortedness {
+            Some(sortedness) => {
+                let metrics_layout = Layout::default()
+                                            .direction(Direction::Horizontal)
+                                            .constraints(
+                                                [
+                                                    Constraint::Min(0),
+                                                    Constraint::Length(40)
+                                                ]
+                                                .as_ref(),
+                                            )
+                                            .split(chart_layout[2]);
+                sortedness_panel(frame, metrics_layout[1], sortedness);
+                metrics_layout[0]
+            }
+            None => chart_layout[2]
+        };
+
+        let chart_area = match panels.tree {
+            Some(tree_view) => {
+                let tree_layout = Layout::default()
+                                         .direction(Direction::Horizontal)
+                                         .constraints(
+                                             [
+                                                 Constraint::Percentage(50),
+                                                 Constraint::Percentage(50)
+                                             ]
+                                             .as_ref(),
+                                         )
+                                         .split(chart_area);
+                tree_view.render(frame, tree_layout[1], numbers);
+                tree_layout[0]
+            }
+            None => chart_area
+        };
         
+        let chart_title = if title.is_empty() {
+            String::new()
+        } else {
+            format!("{} | Nezet: {} (V: valtas) | {} elem", title, view.name(), numbers.len())
+        };
         let sort_block = Block::default()
-                               .title(Span::styled(title, Style::default()
-                                                                .fg(Color::Cyan)
-                                                                .add_modifier(Modifier::BOLD)))
+                               .title(Span::styled(chart_title, Style::default()
+                                                                      .fg(Color::Cyan)
+                                                                      .add_modifier(Modifier::BOLD)))
                                .title_alignment(Alignment::Center)
                                .borders(Borders::ALL)
                                .border_type(BorderType::Rounded)
                                .style(Style::default()
                                             .fg(Color::LightGreen));
-    
-        
-        let tuple_vector: Vec<(&str, u64)> = convert_vector_to_tuple_vector(numbers);
-        let sort_chart: BarChart = BarChart::default()
-                                            .block(sort_block)
-                                            .data(&tuple_vector)
-                                            .bar_width(5)
-                                            .bar_style(Style::default()
-                                                             .fg(Color::LightGreen))
-                                            .value_style(Style::default()
-                                                               .fg(Color::White)
-                                                               .add_modifier(Modifier::BOLD));
-        frame.render_widget(sort_chart, chart_layout[1]);
+
+        chart_view::render_chart(frame, chart_area, sort_block, numbers, view);
     
         let time_block = Block::default()
-                               .title(Span::styled("Vegrehajtasi-ido eredmenyablak", Style::default()
-                                                                                           .fg(Color::Cyan)
-                                                                                           .add_modifier(Modifier::BOLD)))
+                               .title(Span::styled(header, Style::default()
+                                                                 .fg(Color::Cyan)
+                                                                 .add_modifier(Modifier::BOLD)))
                                .borders(Borders::ALL)
                                .border_type(BorderType::Rounded)
                                .style(Style::default()
@@ -236,6 +853,84 @@ pub mod sort_algorithms {
         frame.render_widget(time_span, chart_layout[0]);
     }
 
+    fn invariant_panel<B: Backend, T: SortValue>(frame: &mut Frame<B>, area: Rect, invariants: &InvariantChecker<T>) {
+        let status = match invariants.violation() {
+            Some(violation) => Spans::from(vec![
+                Span::styled("[HIBA] ", Style::default()
+                                              .fg(Color::LightRed)
+                                              .add_modifier(Modifier::BOLD)),
+                Span::from(violation.to_string())
+            ]),
+            None => Spans::from(vec![
+                Span::styled("[OK] ", Style::default()
+                                            .fg(Color::LightGreen)
+                                            .add_modifier(Modifier::BOLD)),
+                Span::from(format!("{} | {} ellenorzes", invariants.description(), invariants.checks()))
+            ])
+        };
+
+        let invariant_paragraph = Paragraph::new(status)
+                                            .style(Style::default()
+                                                         .fg(Color::White))
+                                            .block(Block::default()
+                                                         .title(Span::styled("Invarians", Style::default()
+                                                                                                .fg(Color::Cyan)
+                                                                                                .add_modifier(Modifier::BOLD)))
+                                                         .borders(Borders::ALL)
+                                                         .border_type(BorderType::Rounded)
+                                                         .style(Style::default()
+                                                                      .fg(Color::LightGreen)));
+        frame.render_widget(invariant_paragraph, area);
+    }
+
+    fn sortedness_panel<B: Backend, T: SortValue>(frame: &mut Frame<B>, area: Rect, sortedness: &Sortedness<T>) {
+        let panel_layout = Layout::default()
+                                  .direction(Direction::Vertical)
+                                  .constraints(
+                                      [
+                                          Constraint::Length(3),
+                                          Constraint::Min(0)
+                                      ]
+                                      .as_ref(),
+                                  )
+                                  .split(area);
+
+        let progress_gauge = Gauge::default()
+                                   .block(Block::default()
+                                                .title(Span::styled("Rendezettseg", Style::default()
+                                                                                          .fg(Color::Cyan)
+                                                                                          .add_modifier(Modifier::BOLD)))
+                                                .borders(Borders::ALL)
+                                                .border_type(BorderType::Rounded)
+                                                .style(Style::default()
+                                                             .fg(Color::LightGreen)))
+                                   .gauge_style(Style::default()
+                                                      .fg(Color::LightGreen)
+                                                      .bg(Color::DarkGray))
+                                   .ratio(sortedness.progress().clamp(0.0, 1.0));
+        frame.render_widget(progress_gauge, panel_layout[0]);
+
+        let metric_lines = vec![
+            Spans::from(format!("Inverziok szama: {}", sortedness.inversions())),
+            Spans::from(format!("Novekvo futamok: {}", sortedness.runs())),
+            Spans::from(format!("Leghosszabb novekvo reszsorozat: {}", sortedness.longest_increasing_subsequence())),
+            Spans::from(format!("Vegleges helyen: {} / {}", sortedness.in_final_position(), sortedness.len()))
+        ];
+
+        let metrics_paragraph = Paragraph::new(metric_lines)
+                                          .style(Style::default()
+                                                       .fg(Color::White))
+                                          .block(Block::default()
+                                                       .title(Span::styled("Rendezettsegi mertekek", Style::default()
+                                                                                                           .fg(Color::Cyan)
+                                                                                                           .add_modifier(Modifier::BOLD)))
+                                                       .borders(Borders::ALL)
+                                                       .border_type(BorderType::Rounded)
+                                                       .style(Style::default()
+                                                                    .fg(Color::LightGreen)));
+        frame.render_widget(metrics_paragraph, panel_layout[1]);
+    }
+
     pub mod list_type {
         use tui::widgets::ListState;
 
@@ -0,0 +1,446 @@
+use std::{ collections::VecDeque, time };
+use tui::{ backend::Backend,
+           layout::{ Layout, Direction, Constraint, Alignment },
+           style::{ Color, Style, Modifier },
+           widgets::{ Block, Borders, BorderType, Paragraph, Wrap },
+           text::{ Span, Spans },
+           Frame,
+           Terminal };
+use crate::{ sort_algorithms::{ self, FRAME_INTERVAL_MILLIS, MAX_VECTOR_SIZE },
+             sort_array::Counters };
+
+const ARROW: &str     = "→";
+const NULL_LINK: &str = "∅";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListAlgorithm {
+    NaturalMergeSort,
+    InsertionSort,
+    DequeInsertionSort
+}
+
+impl ListAlgorithm {
+    pub const ALL: [ListAlgorithm; 3] = [
+        ListAlgorithm::NaturalMergeSort,
+        ListAlgorithm::InsertionSort,
+        ListAlgorithm::DequeInsertionSort
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ListAlgorithm::NaturalMergeSort   => "Termeszetes osszefesuleses rendezes (lancolt lista)",
+            ListAlgorithm::InsertionSort      => "Beilleszteses rendezes atlancolassal (lancolt lista)",
+            ListAlgorithm::DequeInsertionSort => "Beilleszteses rendezes ketvegu sorban (VecDeque)"
+        }
+    }
+
+    pub fn key(self) -> &'static str {
+        match self {
+            ListAlgorithm::NaturalMergeSort   => "list_merge",
+            ListAlgorithm::InsertionSort      => "list_insert",
+            ListAlgorithm::DequeInsertionSort => "deque_insert"
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<ListAlgorithm> {
+        ListAlgorithm::ALL.iter()
+                          .copied()
+                          .find(|algorithm| algorithm.key() == key)
+    }
+
+    pub fn sort<O>(self, values: Vec<u64>, observer: O) -> (Vec<u64>, Counters)
+    where
+        O: FnMut(ListEvent, Structure)
+    {
+        match self {
+            ListAlgorithm::NaturalMergeSort   => {
+                let mut list = NodeList::new(values, observer);
+                natural_merge_sort(&mut list);
+                list.into_result()
+            }
+            ListAlgorithm::InsertionSort      => {
+                let mut list = NodeList::new(values, observer);
+                insertion_sort(&mut list);
+                list.into_result()
+            }
+            ListAlgorithm::DequeInsertionSort => deque_insertion_sort(values, observer)
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListEvent {
+    Compare(usize, usize),
+    CompareValue(usize),
+    Relink(usize),
+    Move(usize)
+}
+
+pub enum Structure<'v> {
+    List {
+        values: &'v [u64],
+        next: &'v [Option<usize>],
+        chains: &'v [(String, Option<usize>)]
+    },
+    Deque {
+        deque: &'v VecDeque<u64>,
+        pending: &'v [u64]
+    }
+}
+
+struct NodeList<O> {
+    values: Vec<u64>,
+    next: Vec<Option<usize>>,
+    head: Option<usize>,
+    chains: Vec<(String, Option<usize>)>,
+    counters: Counters,
+    observer: O
+}
+
+impl<O: FnMut(ListEvent, Structure)> NodeList<O> {
+    fn new(values: Vec<u64>, observer: O) -> NodeList<O> {
+        let size = values.len();
+        let next = (0..size).map(|node| (node + 1 < size).then_some(node + 1)).collect();
+
+        NodeList {
+            values,
+            next,
+            head: (size > 0).then_some(0),
+            chains: Vec::new(),
+            counters: Counters::default(),
+            observer
+        }
+    }
+
+    fn notify(&mut self, event: ListEvent) {
+        (self.observer)(event, Structure::List { values: &self.values, next: &self.next, chains: &self.chains });
+    }
+
+    fn next(&self, node: usize) -> Option<usize> {
+        self.next[node]
+    }
+
+    fn less(&mut self, i_node: usize, j_node: usize) -> bool {
+        self.counters.comparisons += 1;
+        self.notify(ListEvent::Compare(i_node, j_node));
+        self.values[i_node] < self.values[j_node]
+    }
+
+    fn set_next(&mut self, node: usize, target: Option<usize>) {
+        self.counters.pointer_updates += 1;
+        self.next[node] = target;
+        self.notify(ListEvent::Relink(node));
+    }
+
+    fn set_head(&mut self, node: usize) -> Option<usize> {
+        self.counters.pointer_updates += 1;
+        self.notify(ListEvent::Relink(node));
+        Some(node)
+    }
+
+    fn set_chains(&mut self, chains: Vec<(String, Option<usize>)>) {
+        self.chains = chains;
+    }
+
+    fn into_result(self) -> (Vec<u64>, Counters) {
+        let mut sorted  = Vec::with_capacity(self.values.len());
+        let mut current = self.head;
+        while let Some(node) = current {
+            sorted.push(self.values[node]);
+            current = self.next[node];
+        }
+
+        (sorted, self.counters)
+    }
+}
+
+fn run_chains(runs: &[Option<usize>], rest: &[Option<usize>]) -> Vec<(String, Option<usize>)> {
+    runs.iter()
+        .enumerate()
+        .map(|(index, run)| (format!("futam {}", index + 1), *run))
+        .chain(rest.iter().map(|rest| (String::from("hatralevo"), *rest)))
+        .collect()
+}
+
+fn natural_merge_sort<O: FnMut(ListEvent, Structure)>(list: &mut NodeList<O>) {
+    let mut runs    = Vec::new();
+    let mut current = list.head;
+
+    while let Some(start) = current {
+        let mut end = start;
+        while let Some(following) = list.next(end) {
+            if list.less(following, end) {
+                break;
+            }
+            end = following;
+        }
+        current = list.next(end);
+        if current.is_some() {
+            list.set_next(end, None);
+        }
+        runs.push(Some(start));
+        list.set_chains(run_chains(&runs, &[current]));
+    }
+
+    while runs.len() > 1 {
+        let mut merged_runs = Vec::with_capacity(runs.len().div_ceil(2));
+        for (index, pair) in runs.chunks(2).enumerate() {
+            let merged = match pair {
+                [left, right] => merge(list, *left, *right),
+                _             => pair[0]
+            };
+            merged_runs.push(merged);
+            list.set_chains(run_chains(&merged_runs, &runs[(2 * (index + 1)).min(runs.len())..]));
+        }
+        runs = merged_runs;
+    }
+
+    list.head = runs.first().copied().flatten();
+    list.set_chains(run_chains(&runs, &[]));
+}
+
+fn merge<O: FnMut(ListEvent, Structure)>(list: &mut NodeList<O>, mut left: Option<usize>, mut right: Option<usize>) -> Option<usize> {
+    let mut head: Option<usize> = None;
+    let mut tail: Option<usize> = None;
+
+    loop {
+        let node = match (left, right) {
+            (Some(left_node), Some(right_node)) => if list.less(right_node, left_node) {
+                right = list.next(right_node);
+                right_node
+            } else {
+                left = list.next(left_node);
+                left_node
+            },
+            (Some(rest), None) | (None, Some(rest)) => {
+                match tail {
+                    Some(tail_node) => list.set_next(tail_node, Some(rest)),
+                    None            => head = list.set_head(rest)
+                }
+                return head;
+            }
+            (None, None) => return head
+        };
+
+        match tail {
+            Some(tail_node) => list.set_next(tail_node, Some(node)),
+            None            => head = list.set_head(node)
+        }
+        tail = Some(node);
+    }
+}
+
+fn insertion_sort<O: FnMut(ListEvent, Structure)>(list: &mut NodeList<O>) {
+    let mut sorted: Option<usize> = None;
+    let mut current               = list.head;
+
+    while let Some(node) = current {
+        current = list.next(node);
+
+        match sorted {
+            Some(sorted_head) if !list.less(node, sorted_head) => {
+                let mut position = sorted_head;
+                while let Some(following) = list.next(position) {
+                    if list.less(node, following) {
+                        break;
+                    }
+                    position = following;
+                }
+                list.set_next(node, list.next(position));
+                list.set_next(position, Some(node));
+            }
+            _ => {
+                list.set_next(node, sorted);
+                sorted = list.set_head(node);
+            }
+        }
+        list.set_chains(vec![(String::from("rendezett"), sorted), (String::from("bemenet"), current)]);
+    }
+
+    list.head = sorted;
+}
+
+fn deque_insertion_sort<O: FnMut(ListEvent, Structure)>(values: Vec<u64>, mut observer: O) -> (Vec<u64>, Counters) {
+    let mut deque    = VecDeque::with_capacity(values.len());
+    let mut counters = Counters::default();
+
+    for (index, value) in values.iter().copied().enumerate() {
+        let pending = &values[index..];
+
+        let position = if deque.is_empty() {
+            0
+        } else {
+            counters.comparisons += 1;
+            observer(ListEvent::CompareValue(0), Structure::Deque { deque: &deque, pending });
+            if value < deque[0] {
+                0
+            } else {
+                counters.comparisons += 1;
+                observer(ListEvent::CompareValue(deque.len() - 1), Structure::Deque { deque: &deque, pending });
+                if value >= deque[deque.len() - 1] {
+                    deque.len()
+                } else {
+                    let (mut low, mut high) = (1, deque.len() - 1);
+                    while low < high {
+                        let middle = low + (high - low) / 2;
+                        counters.comparisons += 1;
+                        observer(ListEvent::CompareValue(middle), Structure::Deque { deque: &deque, pending });
+                        if value < deque[middle] {
+                            high = middle;
+                        } else {
+                            low = middle + 1;
+                        }
+                    }
+                    low
+                }
+            }
+        };
+
+        counters.writes          += 1 + position.min(deque.len() - position) as u64;
+        counters.pointer_updates += 1;
+        deque.insert(position, value);
+        observer(ListEvent::Move(position), Structure::Deque { deque: &deque, pending: &values[index + 1..] });
+    }
+
+    (deque.into_iter().collect(), counters)
+}
+
+fn counters_label(elapsed_time_millis: u128, counters: Counters) -> String {
+    format!("A rendezési algoritmus végrehajtási ideje: {} ms | Osszehasonlitasok: {} | Mutato-frissitesek: {} | Elemmozgatasok: {} | Tovabb: <barmely billentyu>",
+            elapsed_time_millis, counters.comparisons, counters.pointer_updates, counters.writes)
+}
+
+fn node_style(highlighted: bool, relinked: bool) -> Style {
+    match (highlighted, relinked) {
+        (_, true) => Style::default().fg(Color::Black).bg(Color::LightRed).add_modifier(Modifier::BOLD),
+        (true, _) => Style::default().fg(Color::Black).bg(Color::Yellow),
+        _         => Style::default().fg(Color::White)
+    }
+}
+
+fn list_lines<'v>(values: &'v [u64], next: &'v [Option<usize>], chains: &'v [(String, Option<usize>)], event: Option<ListEvent>) -> Vec<Spans<'v>> {
+    let mut has_predecessor = vec![false; values.len()];
+    for target in next.iter().flatten() {
+        has_predecessor[*target] = true;
+    }
+    let detached = (0..values.len()).filter(|node| !has_predecessor[*node])
+                                    .map(|node| (String::from("levalasztott"), Some(node)));
+
+    let mut visited = vec![false; values.len()];
+    let mut lines   = Vec::new();
+    for (label, head) in chains.iter().cloned().chain(detached) {
+        if head.is_some_and(|node| visited[node]) {
+            continue;
+        }
+
+        let mut spans   = vec![Span::styled(format!("{}: ", label), Style::default().fg(Color::Cyan))];
+        let mut current = head;
+        while let Some(node) = current.filter(|node| !visited[*node]) {
+            visited[node]   = true;
+            let highlighted = matches!(event, Some(ListEvent::Compare(i_node, j_node)) if i_node == node || j_node == node);
+            let relinked    = event == Some(ListEvent::Relink(node));
+            spans.push(Span::styled(format!("[{}]", values[node]), node_style(highlighted, relinked)));
+            spans.push(Span::styled(ARROW, Style::default().fg(Color::DarkGray)));
+            current = next[node];
+        }
+        spans.push(Span::styled(if current.is_some() { "..." } else { NULL_LINK }, Style::default().fg(Color::DarkGray)));
+        lines.push(Spans::from(spans));
+    }
+
+    lines
+}
+
+fn deque_lines<'v>(deque: &'v VecDeque<u64>, pending: &'v [u64], event: Option<ListEvent>) -> Vec<Spans<'v>> {
+    let mut deque_spans = vec![Span::styled("elol ⇄ ", Style::default().fg(Color::Cyan))];
+    for (position, value) in deque.iter().enumerate() {
+        let highlighted = event == Some(ListEvent::CompareValue(position));
+        let moved       = event == Some(ListEvent::Move(position));
+        deque_spans.push(Span::styled(format!("[{}]", value), node_style(highlighted, moved)));
+    }
+    deque_spans.push(Span::styled(" ⇄ hatul", Style::default().fg(Color::Cyan)));
+
+    let mut pending_spans = vec![Span::styled("bemenet: ", Style::default().fg(Color::Cyan))];
+    for (index, value) in pending.iter().enumerate() {
+        let incoming = index == 0 && matches!(event, Some(ListEvent::CompareValue(_)));
+        pending_spans.push(Span::styled(format!("{} ", value), node_style(incoming, false)));
+    }
+
+    vec![Spans::from(deque_spans), Spans::from(""), Spans::from(pending_spans)]
+}
+
+fn list_screen<B: Backend>(frame: &mut Frame<B>, structure: Structure, title: &str, header: &str, status: &str, event: Option<ListEvent>) {
+    let list_layout = Layout::default()
+                             .direction(Direction::Vertical)
+                             .constraints(
+                                 [
+                                     Constraint::Length(3),
+                                     Constraint::Min(0)
+                                 ]
+                                 .as_ref(),
+                             )
+                             .split(frame.size());
+
+    let status_paragraph = Paragraph::new(Span::from(status))
+                                     .style(Style::default()
+                                                  .fg(Color::LightRed))
+                                     .block(Block::default()
+                                                  .title(Span::styled(header, Style::default()
+                                                                                    .fg(Color::Cyan)
+                                                                                    .add_modifier(Modifier::BOLD)))
+                                                  .borders(Borders::ALL)
+                                                  .border_type(BorderType::Rounded)
+                                                  .style(Style::default()
+                                                               .fg(Color::LightGreen)));
+    frame.render_widget(status_paragraph, list_layout[0]);
+
+    let lines = match structure {
+        Structure::List { values, next, chains } => list_lines(values, next, chains, event),
+        Structure::Deque { deque, pending }      => deque_lines(deque, pending, event)
+    };
+
+    let structure_paragraph = Paragraph::new(lines)
+                                        .wrap(Wrap { trim: false })
+                                        .block(Block::default()
+                                                     .title(Span::styled(title, Style::default()
+                                                                                      .fg(Color::Cyan)
+                                                                                      .add_modifier(Modifier::BOLD)))
+                                                     .title_alignment(Alignment::Center)
+                                                     .borders(Borders::ALL)
+                                                     .border_type(BorderType::Rounded)
+                                                     .style(Style::default()
+                                                                  .fg(Color::LightGreen)));
+    frame.render_widget(structure_paragraph, list_layout[1]);
+}
+
+pub fn run_list_sort<B: Backend>(terminal: &mut Terminal<B>, algorithm: ListAlgorithm, values: Vec<u64>, seed: u64, input_label: &str) -> (Counters, u128) {
+    let title  = format!("{} | {} elem | sarga: osszehasonlitas, piros: atlancolas / mozgatas", algorithm.name(), values.len());
+    let header = format!("Vegrehajtasi-ido eredmenyablak | Seed: {} | Bemenet: {}", seed, input_label);
+
+    let animate_all    = values.len() <= MAX_VECTOR_SIZE;
+    let mut last_frame = time::Instant::now();
+
+    let current_time = time::Instant::now();
+
+    let (sorted_values, counters) = algorithm.sort(values, |event, structure| {
+        if animate_all || last_frame.elapsed().as_millis() as u64 >= FRAME_INTERVAL_MILLIS {
+            terminal.draw(|frame| list_screen(frame, structure, &title, &header, "", Some(event))).ok();
+            last_frame = time::Instant::now();
+        }
+    });
+
+    let elapsed_time_millis = current_time.elapsed().as_millis();
+
+    let status                   = counters_label(elapsed_time_millis, counters);
+    let chains                   = [(String::from("rendezett"), (!sorted_values.is_empty()).then_some(0))];
+    let next: Vec<Option<usize>> = (0..sorted_values.len()).map(|node| (node + 1 < sorted_values.len()).then_some(node + 1)).collect();
+    let deque: VecDeque<u64>     = sorted_values.iter().copied().collect();
+    let structure = if algorithm == ListAlgorithm::DequeInsertionSort {
+        Structure::Deque { deque: &deque, pending: &[] }
+    } else {
+        Structure::List { values: &sorted_values, next: &next, chains: &chains }
+    };
+    terminal.draw(|frame| list_screen(frame, structure, &title, &header, &status, None)).ok();
+    sort_algorithms::wait_for_key();
+
+    (counters, elapsed_time_millis)
+}
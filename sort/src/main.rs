@@ -1,4 +1,4 @@
-use std::{ error::Error, io };
+use std::{ env, error::Error, io };
 use crossterm::{ execute,
                  terminal::{ enable_raw_mode, disable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen },
                  event::{ self, Event, KeyCode }
@@ -11,30 +11,102 @@ use tui::{ backend::{ Backend, CrosstermBackend },
            Terminal,
            Frame
          };
-use tui_sort_app::sort_algorithms::{ self, list_type };
+use tui_sort_app::{ adversary, array_editor, benchmark, command_line, input_data, prompt, report, sonification, sorting_game,
+                    chart_view::ChartView,
+                    cost_model::{ self, CostModel },
+                    history::{ self, InputKind, RecordedData, RunRecord },
+                    linked_sort::{ self, ListAlgorithm },
+                    signed_data::{ self, DataKind },
+                    string_sort::{ self, StringAlgorithm },
+                    sort_algorithms::{ self, list_type, Algorithm },
+                    sort_value::{ ChartValue, SortValue } };
+
+const MENU_SEPARATOR: &str = "------------------------------";
+
+#[derive(Clone, Copy)]
+enum MenuAction {
+    Sort(Algorithm),
+    SortStrings(StringAlgorithm),
+    SortList(ListAlgorithm),
+    EditInput,
+    LoadFloats,
+    LoadWords,
+    SetSeed,
+    SetSize,
+    SetCostModel,
+    NextDataKind,
+    NextView,
+    ToggleAdversary,
+    ToggleWavExport,
+    Report,
+    Game,
+    History,
+    Separator,
+    Exit
+}
+
+struct RunSettings {
+    seed: u64,
+    size: usize,
+    view: ChartView,
+    adversary_mode: bool,
+    wav_export: bool,
+    data_kind: DataKind,
+    cost_model: CostModel
+}
 
 struct AlgorithmNameList<'a> {
-    items: list_type::StatefulList<&'a str>
+    items: list_type::StatefulList<&'a str>,
+    actions: Vec<MenuAction>
 }
 
 impl<'a> AlgorithmNameList<'a> {
     fn new() -> AlgorithmNameList<'a> {
+        let mut menu_entries: Vec<(&'a str, MenuAction)> = Algorithm::ALL.iter()
+                                                                         .map(|algorithm| (algorithm.name(), MenuAction::Sort(*algorithm)))
+                                                                         .collect();
+        menu_entries.push((MENU_SEPARATOR, MenuAction::Separator));
+        menu_entries.extend(StringAlgorithm::ALL.iter()
+                                                .map(|algorithm| (algorithm.name(), MenuAction::SortStrings(*algorithm))));
+        menu_entries.push((MENU_SEPARATOR, MenuAction::Separator));
+        menu_entries.extend(ListAlgorithm::ALL.iter()
+                                              .map(|algorithm| (algorithm.name(), MenuAction::SortList(*algorithm))));
+        menu_entries.extend([
+            (MENU_SEPARATOR, MenuAction::Separator),
+            ("Bemeneti tomb szerkesztese / betoltese", MenuAction::EditInput),
+            ("Meresi adatok (f64) betoltese fajlbol (ures: veletlen)", MenuAction::LoadFloats),
+            ("Szolista betoltese fajlbol (ures: beepitett szolista)", MenuAction::LoadWords),
+            ("Seed beallitasa", MenuAction::SetSeed),
+            ("Tombmeret beallitasa", MenuAction::SetSize),
+            ("Koltsegmodell sulyai (osszehasonlitas / csere / iras / cache-hiba)", MenuAction::SetCostModel),
+            ("Adattipus valtasa (u64 / i64 / f64)", MenuAction::NextDataKind),
+            ("Nezet valtasa (oszlop / pont / szinspektrum / kor / elteres)", MenuAction::NextView),
+            ("Ellenseges (worst-case) bemenet be / ki", MenuAction::ToggleAdversary),
+            ("Hang export (WAV) a futasokhoz be / ki", MenuAction::ToggleWavExport),
+            ("Jelentes: legjobb / atlagos / legrosszabb eset", MenuAction::Report),
+            ("Rendezo jatek (kezi rendezes)", MenuAction::Game),
+            ("Futasi elozmenyek / ranglista", MenuAction::History),
+            (MENU_SEPARATOR, MenuAction::Separator),
+            ("Kilepes: <ESC>", MenuAction::Exit)
+        ]);
+
+        let (names, actions): (Vec<&'a str>, Vec<MenuAction>) = menu_entries.into_iter().unzip();
+
         AlgorithmNameList {
-            items: list_type::StatefulList::with_items(vec![
-                "Egyszeru cseres rendezes",
-                "Minimum kivalasztasos rendezes",
-                "Buborekos rendezes",
-                "Javitott buborekos rendezes",
-                "Beilleszteses rendezes",
-                "Gnome rendezes",
-                "------------------------------",
-                "Kilepes: <ESC>"
-            ])
+            items: list_type::StatefulList::with_items(names),
+            actions
         }
     }
+
+    fn selected_action(&self) -> MenuAction {
+        self.items
+            .state
+            .selected()
+            .map_or(MenuAction::Separator, |index| self.actions[index])
+    }
 }
 
-fn main_screen<B: Backend>(frame: &mut Frame<B>, list_of_sort_algorithm_names: &mut AlgorithmNameList) {
+fn main_screen<B: Backend>(frame: &mut Frame<B>, list_of_sort_algorithm_names: &mut AlgorithmNameList, input_numbers: &[u64], input_floats: &[f64], settings: &RunSettings, message: &str) {
     let main_layout = Layout::default()
                              .direction(Direction::Vertical)
                              .constraints([Constraint::Percentage(100)].as_ref())
@@ -50,13 +122,31 @@ fn main_screen<B: Backend>(frame: &mut Frame<B>, list_of_sort_algorithm_names: &
                                                            )
                                                            .collect();
 
+    let (input_label, size) = if settings.adversary_mode {
+        ("ellenseges / worst-case", settings.size)
+    } else if settings.data_kind == DataKind::Float && !input_floats.is_empty() {
+        ("betoltott meresi adatok", input_floats.len())
+    } else if settings.data_kind != DataKind::Unsigned || input_numbers.is_empty() {
+        ("veletlen szamok", settings.size)
+    } else {
+        ("sajat tomb", input_numbers.len())
+    };
+    let mut title = format!("Rendezesi algoritmusok (bemenet: {}, {}, {} elem, seed: {}, nezet: {})",
+                            input_label, settings.data_kind.name(), size, settings.seed, settings.view.name());
+    if settings.wav_export {
+        title += " | WAV export: be";
+    }
+    if !message.is_empty() {
+        title += &format!(" | {}", message);
+    }
+
     let list_block = List::new(items)
                           .block(Block::default()
                                        .borders(Borders::ALL)
                                        .border_type(BorderType::Rounded)
                                        .border_style(Style::default()
                                                            .fg(Color::Green))
-                                       .title(Span::styled("Rendezesi algoritmusok", Style::default()
+                                       .title(Span::styled(title, Style::default()
                                                                                            .fg(Color::Cyan)
                                                                                            .add_modifier(Modifier::BOLD))))
                           .highlight_style(Style::default()
@@ -68,27 +158,183 @@ fn main_screen<B: Backend>(frame: &mut Frame<B>, list_of_sort_algorithm_names: &
     frame.render_stateful_widget(list_block, main_layout[0], &mut list_of_sort_algorithm_names.items.state);
 }
 
-fn run_sort_app<B: Backend>(terminal: &mut Terminal<B>, numbers: &mut Vec<u64>) -> io::Result<()> { 
+fn parse_seed(input: &str) -> Result<u64, String> {
+    if input.is_empty() {
+        return Ok(sort_algorithms::random_seed());
+    }
+
+    input.parse::<u64>()
+         .map_err(|_| format!("Ervenytelen seed: \"{}\" (0..{})", input, u64::MAX))
+}
+
+fn parse_float_file(input: &str) -> Result<Vec<f64>, String> {
+    if input.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    input_data::load_floats_from_file(input)
+}
+
+fn parse_word_file(input: &str) -> Result<Vec<String>, String> {
+    if input.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    input_data::load_words_from_file(input)
+}
+
+fn prepare_input(algorithm: Algorithm, numbers: &mut Vec<u64>, input_numbers: &[u64], settings: &RunSettings) -> (String, InputKind) {
+    if settings.adversary_mode {
+        *numbers = adversary::worst_case_input(algorithm, settings.size, settings.seed);
+        let input_label = match adversary::save_input(algorithm, numbers, settings.seed) {
+            Ok(file_name)      => format!("ellenseges, mentve: {}", file_name),
+            Err(error_message) => format!("ellenseges, mentes sikertelen: {}", error_message)
+        };
+        (input_label, InputKind::Adversary)
+    } else {
+        sort_algorithms::prepare_numbers(numbers, input_numbers, settings.size, settings.seed);
+        if input_numbers.is_empty() {
+            (String::from("veletlen"), InputKind::Random)
+        } else {
+            (String::from("sajat tomb"), InputKind::CustomArray)
+        }
+    }
+}
+
+fn record_run(record: RunRecord) -> String {
+    match history::append_record(&record) {
+        Ok(())             => String::new(),
+        Err(error_message) => format!("Elozmeny mentese sikertelen: {}", error_message)
+    }
+}
+
+fn join_messages(messages: &[String]) -> String {
+    messages.iter()
+            .filter(|message| !message.is_empty())
+            .cloned()
+            .collect::<Vec<String>>()
+            .join(" | ")
+}
+
+fn wav_export_message(result: io::Result<String>) -> String {
+    match result {
+        Ok(file_name)      => format!("Hangfajl mentve: {}", file_name),
+        Err(error_message) => format!("Hangfajl mentese sikertelen: {}", error_message)
+    }
+}
+
+fn sort_numbers<B: Backend>(terminal: &mut Terminal<B>, algorithm: Algorithm, numbers: &mut Vec<u64>, input_numbers: &[u64], input_floats: &[f64], settings: &mut RunSettings) -> String {
+    match settings.data_kind {
+        DataKind::Signed if settings.adversary_mode => sort_signed(terminal, algorithm, signed_data::adversary_values::<i64>(algorithm, settings.size, settings.seed), InputKind::Adversary, settings),
+        DataKind::Signed                            => sort_signed(terminal, algorithm, signed_data::generate_signed_values(settings.size, settings.seed), InputKind::Random, settings),
+        DataKind::Float if settings.adversary_mode  => sort_signed(terminal, algorithm, signed_data::adversary_values::<f64>(algorithm, settings.size, settings.seed), InputKind::Adversary, settings),
+        DataKind::Float if !input_floats.is_empty() => sort_signed(terminal, algorithm, input_floats.to_vec(), InputKind::CustomArray, settings),
+        DataKind::Float                             => sort_signed(terminal, algorithm, signed_data::generate_float_values(settings.size, settings.seed), InputKind::Random, settings),
+        DataKind::Unsigned                          => {
+            let (input_label, input_kind) = prepare_input(algorithm, numbers, input_numbers, settings);
+            let size                      = numbers.len();
+            let wav_input                 = settings.wav_export.then(|| numbers.clone());
+            let (counters, millis)        = sort_algorithms::run_sort_algorithm(algorithm, numbers, terminal, settings.seed, &input_label, &mut settings.view);
+            let wav_message               = wav_input.map(|input| wav_export_message(sonification::export_wav(algorithm, input, u64::cmp, |value| *value as f64, DataKind::Unsigned, settings.seed)))
+                                                     .unwrap_or_default();
+            join_messages(&[record_run(RunRecord::new(algorithm.key(), input_kind, RecordedData::Numbers(DataKind::Unsigned), size, settings.seed, counters, millis)), wav_message])
+        }
+    }
+}
+
+fn sort_signed<B: Backend, T: ChartValue>(terminal: &mut Terminal<B>, algorithm: Algorithm, mut values: Vec<T>, input_kind: InputKind, settings: &mut RunSettings) -> String {
+    let size               = values.len();
+    let wav_input          = settings.wav_export.then(|| values.clone());
+    let (counters, millis) = sort_algorithms::run_sort_algorithm(algorithm, &mut values, terminal, settings.seed, input_kind.name(), &mut settings.view);
+    let wav_message        = wav_input.map(|input| wav_export_message(sonification::export_wav(algorithm, input, T::compare, T::plot_value, settings.data_kind, settings.seed)))
+                                      .unwrap_or_default();
+    join_messages(&[record_run(RunRecord::new(algorithm.key(), input_kind, RecordedData::Numbers(settings.data_kind), size, settings.seed, counters, millis)), wav_message])
+}
+
+fn sort_words<B: Backend>(terminal: &mut Terminal<B>, algorithm: StringAlgorithm, input_words: &[String], seed: u64) -> io::Result<String> {
+    let words      = string_sort::prepare_words(input_words, seed);
+    let input_kind = if input_words.is_empty() { InputKind::BuiltinWords } else { InputKind::CustomWords };
+    let (counters, millis) = string_sort::run_string_sort(terminal, algorithm, &words, seed, input_kind.name())?;
+    Ok(record_run(RunRecord::new(algorithm.key(), input_kind, RecordedData::Text, words.len(), seed, counters, millis)))
+}
+
+fn sort_list<B: Backend>(terminal: &mut Terminal<B>, algorithm: ListAlgorithm, input_numbers: &[u64], settings: &RunSettings) -> String {
+    let mut values = Vec::new();
+    sort_algorithms::prepare_numbers(&mut values, input_numbers, settings.size, settings.seed);
+    let input_kind = if input_numbers.is_empty() { InputKind::Random } else { InputKind::CustomArray };
+
+    let size               = values.len();
+    let (counters, millis) = linked_sort::run_list_sort(terminal, algorithm, values, settings.seed, input_kind.name());
+    record_run(RunRecord::new(algorithm.key(), input_kind, RecordedData::Numbers(DataKind::Unsigned), size, settings.seed, counters, millis))
+}
+
+fn relaunch<B: Backend>(terminal: &mut Terminal<B>, record: &RunRecord, numbers: &mut Vec<u64>, settings: &mut RunSettings) -> io::Result<String> {
+    settings.seed = record.seed;
+
+    if let (Some(algorithm), RecordedData::Numbers(data_kind)) = (Algorithm::from_key(&record.algorithm_key), record.data) {
+        settings.size           = record.size;
+        settings.adversary_mode = record.input_kind == InputKind::Adversary;
+        settings.data_kind      = data_kind;
+        Ok(sort_numbers(terminal, algorithm, numbers, &[], &[], settings))
+    } else if let Some(algorithm) = StringAlgorithm::from_key(&record.algorithm_key) {
+        sort_words(terminal, algorithm, &[], record.seed)
+    } else if let Some(algorithm) = ListAlgorithm::from_key(&record.algorithm_key) {
+        settings.size = record.size;
+        Ok(sort_list(terminal, algorithm, &[], settings))
+    } else {
+        Ok(format!("Ismeretlen algoritmus: {}", record.algorithm_key))
+    }
+}
+
+fn run_sort_app<B: Backend>(terminal: &mut Terminal<B>, numbers: &mut Vec<u64>, mut settings: RunSettings) -> io::Result<()> { 
     let mut list_of_sort_algorithm_names = AlgorithmNameList::new();
     list_of_sort_algorithm_names.items.state.select(Some(0));  
 
+    let mut input_numbers: Vec<u64>  = Vec::new();
+    let mut input_floats: Vec<f64>   = Vec::new();
+    let mut input_words: Vec<String> = Vec::new();
+    let mut message                  = String::new();
+
     loop {     
-        terminal.draw(|frame| main_screen(frame, &mut list_of_sort_algorithm_names))?;
+        terminal.draw(|frame| main_screen(frame, &mut list_of_sort_algorithm_names, &input_numbers, &input_floats, &settings, &message))?;
 
         if let Event::Key(key) = event::read()? {
             match key.code {
                 KeyCode::Esc       => return Ok(()),
                 KeyCode::Down      => list_of_sort_algorithm_names.items.next(),
                 KeyCode::Up        => list_of_sort_algorithm_names.items.previous(),
-                KeyCode::Enter     => match list_of_sort_algorithm_names.items.state.selected() {
-                    Some(0) => sort_algorithms::simple_sort(numbers, terminal),
-                    Some(1) => sort_algorithms::min_sort(numbers, terminal),
-                    Some(2) => sort_algorithms::bubble_sort(numbers, terminal),
-                    Some(3) => sort_algorithms::opt_bubble_sort(numbers, terminal),
-                    Some(4) => sort_algorithms::insert_sort(numbers, terminal),
-                    Some(5) => sort_algorithms::gnome_sort(numbers, terminal),
-                    Some(7) => return Ok(()),
-                    _ => {}
+                KeyCode::Enter     => match list_of_sort_algorithm_names.selected_action() {
+                    MenuAction::Sort(algorithm)        => message = sort_numbers(terminal, algorithm, numbers, &input_numbers, &input_floats, &mut settings),
+                    MenuAction::SortStrings(algorithm) => message = sort_words(terminal, algorithm, &input_words, settings.seed)?,
+                    MenuAction::SortList(algorithm)    => message = sort_list(terminal, algorithm, &input_numbers, &settings),
+                    MenuAction::EditInput       => array_editor::run_array_editor(terminal, &mut input_numbers)?,
+                    MenuAction::LoadFloats      => if let Some(floats) = prompt::read_value(terminal, "Meresi adatok fajl eleresi utja (ures: veletlen f64 adatok)", "", parse_float_file)? {
+                        input_floats       = floats;
+                        settings.data_kind = DataKind::Float;
+                    }
+                    MenuAction::LoadWords       => if let Some(words) = prompt::read_value(terminal, "Szolista fajl eleresi utja (ures: beepitett szolista)", "", parse_word_file)? {
+                        input_words = words;
+                    }
+                    MenuAction::SetSeed         => if let Some(new_seed) = prompt::read_value(terminal, "Seed (ures: uj veletlen seed)", &settings.seed.to_string(), parse_seed)? {
+                        settings.seed = new_seed;
+                    }
+                    MenuAction::SetSize         => if let Some(new_size) = prompt::read_value(terminal, "Tombmeret (1..10000)", &settings.size.to_string(), command_line::parse_size)? {
+                        settings.size = new_size;
+                    }
+                    MenuAction::SetCostModel    => if let Some(cost_model) = prompt::read_value(terminal, "Sulyok: osszehasonlitas csere iras cache-hiba (pl. 10 1 1 0: draga osszehasonlitas)", &settings.cost_model.weights(), cost_model::parse_cost_model)? {
+                        settings.cost_model = cost_model;
+                    }
+                    MenuAction::NextDataKind    => settings.data_kind = settings.data_kind.next(),
+                    MenuAction::NextView        => settings.view = settings.view.next(),
+                    MenuAction::ToggleAdversary => settings.adversary_mode = !settings.adversary_mode,
+                    MenuAction::ToggleWavExport => settings.wav_export = !settings.wav_export,
+                    MenuAction::Report          => report::run_report(terminal, settings.seed, &settings.cost_model)?,
+                    MenuAction::Game            => sorting_game::run_sorting_game(terminal, settings.seed)?,
+                    MenuAction::History         => if let Some(record) = history::run_history(terminal)? {
+                        message = relaunch(terminal, &record, numbers, &mut settings)?;
+                    }
+                    MenuAction::Exit            => return Ok(()),
+                    MenuAction::Separator       => {}
                 }
                 _ => {}
             }
@@ -97,6 +343,61 @@ fn run_sort_app<B: Backend>(terminal: &mut Terminal<B>, numbers: &mut Vec<u64>)
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
+    let options = match command_line::parse_arguments(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error_message) => {
+            eprintln!("{}\n\n{}", error_message, command_line::USAGE);
+            return Ok(());
+        }
+    };
+
+    if options.show_help {
+        println!("{}", command_line::USAGE);
+        return Ok(());
+    }
+
+    if options.report {
+        let seed       = options.seed.unwrap_or_else(sort_algorithms::random_seed);
+        let cost_model = options.cost_model.unwrap_or_default();
+        let rows       = report::generate_report(&report::REPORT_SIZES, seed, &cost_model);
+        print!("{}", report::to_markdown(&rows, seed, &cost_model));
+        println!("\n{}\n{}", report::save_markdown(&rows, seed, &cost_model)?, report::save_csv(&rows, seed)?);
+        return Ok(());
+    }
+
+    if options.bench {
+        let seed = options.seed.unwrap_or_else(sort_algorithms::random_seed);
+        let rows = benchmark::generate_benchmark(&benchmark::BENCHMARK_SIZES, seed);
+        print!("{}", benchmark::to_markdown(&rows, &benchmark::BENCHMARK_SIZES, seed));
+        println!("\n{}", benchmark::save_markdown(&rows, &benchmark::BENCHMARK_SIZES, seed)?);
+        return Ok(());
+    }
+
+    if let Some(algorithm) = options.wav_algorithm {
+        let seed        = options.seed.unwrap_or_else(sort_algorithms::random_seed);
+        let size        = options.size.unwrap_or(sort_algorithms::MAX_VECTOR_SIZE);
+        let data_kind   = options.data_kind.unwrap_or(DataKind::Unsigned);
+        let mut numbers = Vec::new();
+        sort_algorithms::prepare_numbers(&mut numbers, &[], size, seed);
+        let file_name = match data_kind {
+            DataKind::Unsigned => sonification::export_wav(algorithm, numbers, u64::cmp, |value| *value as f64, data_kind, seed)?,
+            DataKind::Signed   => sonification::export_wav(algorithm, signed_data::generate_signed_values(size, seed), i64::compare, i64::plot_value, data_kind, seed)?,
+            DataKind::Float    => sonification::export_wav(algorithm, signed_data::generate_float_values(size, seed), f64::compare, f64::plot_value, data_kind, seed)?
+        };
+        println!("{}", file_name);
+        return Ok(());
+    }
+
+    let settings = RunSettings {
+        seed: options.seed.unwrap_or_else(sort_algorithms::random_seed),
+        size: options.size.unwrap_or(sort_algorithms::MAX_VECTOR_SIZE),
+        view: options.view.unwrap_or(ChartView::Bars),
+        adversary_mode: false,
+        wav_export: false,
+        data_kind: options.data_kind.unwrap_or(DataKind::Unsigned),
+        cost_model: options.cost_model.unwrap_or_default()
+    };
+
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
@@ -105,7 +406,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     let mut terminal = Terminal::new(backend)?;
 
     let mut numbers: Vec<u64> = Vec::new();
-    let sort_app_result = run_sort_app(&mut terminal, &mut numbers);
+    let sort_app_result = run_sort_app(&mut terminal, &mut numbers, settings);
 
     disable_raw_mode()?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
@@ -0,0 +1,255 @@
+use std::{ cmp::Ordering, collections::VecDeque, fs, io };
+use crossterm::event::KeyCode;
+use tui::{ backend::Backend,
+           layout::Rect,
+           style::{ Color, Style, Modifier },
+           text::{ Span, Spans },
+           widgets::{ Block, Borders, BorderType, Paragraph },
+           Frame };
+use crate::{ sort_array::Operation, sort_value::SortValue };
+
+const MAX_NARRATION_ENTRIES: usize = 100_000;
+pub const NARRATION_HEIGHT: u16    = 10;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NarrationKind {
+    Comparison,
+    Movement,
+    Structure
+}
+
+impl NarrationKind {
+    pub const ALL: [NarrationKind; 3] = [NarrationKind::Comparison, NarrationKind::Movement, NarrationKind::Structure];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            NarrationKind::Comparison => "osszehasonlitas",
+            NarrationKind::Movement   => "mozgatas",
+            NarrationKind::Structure  => "szerkezet"
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            NarrationKind::Comparison => Color::White,
+            NarrationKind::Movement   => Color::Yellow,
+            NarrationKind::Structure  => Color::LightBlue
+        }
+    }
+}
+
+struct NarrationEntry<T> {
+    step: u64,
+    operation: Operation,
+    values: [Option<T>; 2],
+    swapped: bool
+}
+
+impl<T: SortValue> NarrationEntry<T> {
+    fn kind(&self) -> NarrationKind {
+        match self.operation {
+            Operation::Compare(_, _) | Operation::CompareValue(_) | Operation::CharRead(_, _)            => NarrationKind::Comparison,
+            Operation::Swap(_, _) | Operation::Read(_) | Operation::Write(_) | Operation::Reverse(_, _) => NarrationKind::Movement,
+            _                                                                                          => NarrationKind::Structure
+        }
+    }
+
+    fn matches(&self, filter: Option<NarrationKind>) -> bool {
+        match filter {
+            None                          => true,
+            Some(NarrationKind::Movement) => self.kind() == NarrationKind::Movement || self.swapped,
+            Some(kind)                    => self.kind() == kind
+        }
+    }
+
+    fn label(&self, slot: usize) -> String {
+        self.values[slot].as_ref().map_or_else(String::new, SortValue::label)
+    }
+
+    fn text(&self) -> String {
+        let text = match self.operation {
+            Operation::Compare(i_index, j_index)  => {
+                let relation = match (&self.values[0], &self.values[1]) {
+                    (Some(x_value), Some(y_value)) => match x_value.compare(y_value) {
+                        Ordering::Less    => "<",
+                        Ordering::Equal   => "=",
+                        Ordering::Greater => ">"
+                    },
+                    _                              => "?"
+                };
+                let swap = if self.swapped { " -> csere" } else { "" };
+                format!("osszehasonlitas: a[{}]={} {} a[{}]={}{}", i_index, self.label(0), relation, j_index, self.label(1), swap)
+            }
+            Operation::CompareValue(index)        => format!("osszehasonlitas a kivett ertekkel: a[{}]={}", index, self.label(0)),
+            Operation::Swap(i_index, j_index)     => format!("csere: a[{}]={} <-> a[{}]={}", i_index, self.label(0), j_index, self.label(1)),
+            Operation::Read(index)                => format!("kiolvasas: a[{}]={}", index, self.label(0)),
+            Operation::Write(index)               => format!("iras: a[{}] := {}", index, self.label(0)),
+            Operation::Reverse(low, high)         => format!("forditas: a[{}..{})", low, high),
+            Operation::CharRead(index, depth)     => format!("karakter kiolvasasa: a[{}], {}. pozicio", index, depth),
+            Operation::SiftUp(index)              => format!("szitalas felfele: a[{}]={}", index, self.label(0)),
+            Operation::SiftDown(root, heap_size)  => format!("szitalas lefele a[{}]={} elemtol, kupacmeret: {}", root, self.label(0), heap_size),
+            Operation::TreeInsert(index, _)       => format!("beszuras a keresofaba: a[{}]={}", index, self.label(0)),
+            Operation::ActiveRange(low, high)     => format!("aktiv tartomany: [{}..{})", low, high),
+            Operation::Inserting(index)           => format!("beszurando elem: a[{}]={}", index, self.label(0)),
+            Operation::Merging(low, middle, high) => format!("rendezett futamok: [{}..{}) es [{}..{}) -> osszefesules", low, middle, middle, high),
+            Operation::PivotPlaced(index)         => format!("pivot {} a vegleges helyere kerult: {}. index", self.label(0), index)
+        };
+
+        format!("{:>7}. {}", self.step, text)
+    }
+}
+
+pub struct Narrator<T> {
+    entries: VecDeque<NarrationEntry<T>>,
+    dropped: u64,
+    step: u64,
+    last_compare: Option<(usize, usize)>,
+    filter: Option<NarrationKind>,
+    scroll: usize,
+    message: String
+}
+
+impl<T: SortValue> Default for Narrator<T> {
+    fn default() -> Narrator<T> {
+        Narrator::new()
+    }
+}
+
+impl<T: SortValue> Narrator<T> {
+    pub fn new() -> Narrator<T> {
+        Narrator {
+            entries: VecDeque::new(),
+            dropped: 0,
+            step: 0,
+            last_compare: None,
+            filter: None,
+            scroll: 0,
+            message: String::new()
+        }
+    }
+
+    pub fn next_filter(&mut self) {
+        self.filter = match self.filter {
+            None       => Some(NarrationKind::ALL[0]),
+            Some(kind) => NarrationKind::ALL.iter()
+                                            .position(|candidate| *candidate == kind)
+                                            .and_then(|index| NarrationKind::ALL.get(index + 1))
+                                            .copied()
+        };
+        self.scroll = 0;
+    }
+
+    pub fn scroll_up(&mut self, lines: usize) {
+        let matching = self.entries.iter().filter(|entry| entry.matches(self.filter)).count();
+        self.scroll  = (self.scroll + lines).min(matching.saturating_sub(1));
+    }
+
+    pub fn scroll_down(&mut self, lines: usize) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+
+    pub fn update(&mut self, operation: Operation, items: &[T]) {
+        let last_compare = self.last_compare.take();
+        let values       = match operation {
+            Operation::Compare(i_index, j_index) => {
+                self.last_compare = Some((i_index, j_index));
+                [Some(items[i_index].clone()), Some(items[j_index].clone())]
+            }
+            Operation::Swap(i_index, j_index) if last_compare == Some((i_index, j_index)) || last_compare == Some((j_index, i_index)) => {
+                if let Some(entry) = self.entries.back_mut() {
+                    entry.swapped = true;
+                }
+                return;
+            }
+            Operation::Swap(i_index, j_index)    => [Some(items[j_index].clone()), Some(items[i_index].clone())],
+            Operation::CompareValue(index) | Operation::Read(index) | Operation::Write(index) | Operation::SiftUp(index) | Operation::SiftDown(index, _) |
+            Operation::TreeInsert(index, _) | Operation::Inserting(index) | Operation::PivotPlaced(index) => [Some(items[index].clone()), None],
+            Operation::Reverse(_, _) | Operation::CharRead(_, _) | Operation::ActiveRange(_, _) | Operation::Merging(_, _, _)        => [None, None]
+        };
+
+        self.step += 1;
+        if self.entries.len() == MAX_NARRATION_ENTRIES {
+            self.entries.pop_front();
+            self.dropped += 1;
+        }
+        self.entries.push_back(NarrationEntry { step: self.step, operation, values, swapped: false });
+    }
+
+    pub fn export(&self, algorithm_name: &str, algorithm_key: &str, size: usize, seed: u64) -> io::Result<String> {
+        let file_name = format!("rendezes_narracio_{}_{}_{}.txt", algorithm_key, size, seed);
+        let mut lines = vec![
+            format!("Algoritmus: {} | {} elem | Seed: {} | Szuro: {}", algorithm_name, size, seed, filter_name(self.filter)),
+            String::new()
+        ];
+        if self.dropped > 0 {
+            lines.push(format!("(az elso {} bejegyzes nem fert el a naploban)", self.dropped));
+        }
+        lines.extend(self.entries.iter()
+                                 .filter(|entry| entry.matches(self.filter))
+                                 .map(NarrationEntry::text));
+
+        fs::write(&file_name, lines.join("\n") + "\n")?;
+        Ok(file_name)
+    }
+
+    pub fn set_message(&mut self, message: String) {
+        self.message = message;
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode, algorithm_name: &str, algorithm_key: &str, size: usize, seed: u64) -> bool {
+        match key {
+            KeyCode::Up                             => self.scroll_up(1),
+            KeyCode::Down                           => self.scroll_down(1),
+            KeyCode::PageUp                         => self.scroll_up(NARRATION_HEIGHT as usize),
+            KeyCode::PageDown                       => self.scroll_down(NARRATION_HEIGHT as usize),
+            KeyCode::Char('f') | KeyCode::Char('F') => self.next_filter(),
+            KeyCode::Char('e') | KeyCode::Char('E') => {
+                let message = match self.export(algorithm_name, algorithm_key, size, seed) {
+                    Ok(file_name)      => format!("mentve: {}", file_name),
+                    Err(error_message) => format!("mentes sikertelen: {}", error_message)
+                };
+                self.set_message(message);
+            }
+            _ => return false
+        }
+
+        true
+    }
+
+    pub fn render<B: Backend>(&self, frame: &mut Frame<B>, area: Rect) {
+        let mut title = format!("Narracio | szuro: {} (F: valtas)", filter_name(self.filter));
+        if self.scroll > 0 {
+            title += &format!(" | {} sorral feljebb", self.scroll);
+        }
+        if !self.message.is_empty() {
+            title += &format!(" | {}", self.message);
+        }
+
+        let visible_lines = area.height.saturating_sub(2) as usize;
+        let mut lines: Vec<Spans> = self.entries
+                                        .iter()
+                                        .rev()
+                                        .filter(|entry| entry.matches(self.filter))
+                                        .skip(self.scroll)
+                                        .take(visible_lines)
+                                        .map(|entry| Spans::from(Span::styled(entry.text(), Style::default()
+                                                                                                   .fg(entry.kind().color()))))
+                                        .collect();
+        lines.reverse();
+
+        let narration_paragraph = Paragraph::new(lines)
+                                            .block(Block::default()
+                                                         .title(Span::styled(title, Style::default()
+                                                                                          .fg(Color::Cyan)
+                                                                                          .add_modifier(Modifier::BOLD)))
+                                                         .borders(Borders::ALL)
+                                                         .border_type(BorderType::Rounded)
+                                                         .style(Style::default()
+                                                                      .fg(Color::LightGreen)));
+        frame.render_widget(narration_paragraph, area);
+    }
+}
+
+fn filter_name(filter: Option<NarrationKind>) -> &'static str {
+    filter.map_or("minden", NarrationKind::name)
+}
@@ -0,0 +1,78 @@
+use std::io;
+use crossterm::event::{ self, Event, KeyCode };
+use tui::{ backend::Backend,
+           layout::{ Layout, Direction, Constraint },
+           style::{ Color, Style, Modifier },
+           widgets::{ Block, Borders, BorderType, Paragraph },
+           text::{ Span, Spans },
+           Frame,
+           Terminal };
+
+fn prompt_screen<B: Backend>(frame: &mut Frame<B>, title: &str, input: &str, message: &str) {
+    let prompt_layout = Layout::default()
+                               .direction(Direction::Vertical)
+                               .constraints(
+                                   [
+                                       Constraint::Length(3),
+                                       Constraint::Length(3),
+                                       Constraint::Min(0)
+                                   ]
+                                   .as_ref(),
+                               )
+                               .split(frame.size());
+
+    let input_paragraph = Paragraph::new(Spans::from(vec![
+                                             Span::styled("> ", Style::default()
+                                                                      .fg(Color::Cyan)),
+                                             Span::from(input)
+                                         ]))
+                                    .style(Style::default()
+                                                 .fg(Color::White))
+                                    .block(Block::default()
+                                                 .borders(Borders::ALL)
+                                                 .border_type(BorderType::Rounded)
+                                                 .border_style(Style::default()
+                                                                     .fg(Color::Green))
+                                                 .title(Span::styled(title, Style::default()
+                                                                                   .fg(Color::Cyan)
+                                                                                   .add_modifier(Modifier::BOLD))));
+    frame.render_widget(input_paragraph, prompt_layout[0]);
+
+    let message_paragraph = Paragraph::new(Span::from(message))
+                                      .style(Style::default()
+                                                   .fg(Color::LightRed))
+                                      .block(Block::default()
+                                                   .borders(Borders::ALL)
+                                                   .border_type(BorderType::Rounded)
+                                                   .border_style(Style::default()
+                                                                       .fg(Color::Green))
+                                                   .title(Span::styled("Jovahagyas: <ENTER> | Megse: <ESC>", Style::default()
+                                                                                                                   .fg(Color::Cyan)
+                                                                                                                   .add_modifier(Modifier::BOLD))));
+    frame.render_widget(message_paragraph, prompt_layout[1]);
+}
+
+pub fn read_value<B: Backend, T, F>(terminal: &mut Terminal<B>, title: &str, initial_input: &str, parse: F) -> io::Result<Option<T>>
+where
+    F: Fn(&str) -> Result<T, String>
+{
+    let mut input   = String::from(initial_input);
+    let mut message = String::new();
+
+    loop {
+        terminal.draw(|frame| prompt_screen(frame, title, &input, &message))?;
+
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Esc       => return Ok(None),
+                KeyCode::Enter     => match parse(input.trim()) {
+                    Ok(value)          => return Ok(Some(value)),
+                    Err(error_message) => message = error_message
+                }
+                KeyCode::Backspace => { input.pop(); }
+                KeyCode::Char(character) => input.push(character),
+                _ => {}
+            }
+        }
+    }
+}
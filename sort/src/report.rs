@@ -0,0 +1,322 @@
+use std::{ fs, io, time };
+use crossterm::event::{ self, Event, KeyCode };
+use rand::seq::SliceRandom;
+use tui::{ backend::Backend,
+           layout::{ Layout, Direction, Constraint },
+           style::{ Color, Style, Modifier },
+           widgets::{ Block, Borders, BorderType, Cell, Paragraph, Row, Table, TableState },
+           text::Span,
+           Frame,
+           Terminal };
+use crate::{ adversary,
+             cost_model::{ CacheSimulator, CostModel, CACHE_LINES, CACHE_LINE_ITEMS },
+             sort_algorithms::{ self, Algorithm },
+             sort_array::{ Counters, SortArray } };
+
+pub const REPORT_SIZES: [usize; 3]  = [10, 100, 1000];
+const AVERAGE_CASE_TRIALS: u64      = 5;
+const STABILITY_CHECK_SIZE: usize   = 200;
+const STABILITY_CHECK_KEYS: u64     = 8;
+const STABILITY_CHECK_TRIALS: u64   = 3;
+const REPORT_HEADER: [&str; 12]     = ["Algoritmus", "Eset", "Meret", "Osszehasonlitas", "Csere", "Iras", "Forditas", "Cache-hiba", "Sulyozott koltseg", "Ido (us)", "Stabil", "Helyben"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputCase {
+    Best,
+    Average,
+    Worst
+}
+
+impl InputCase {
+    pub const ALL: [InputCase; 3] = [InputCase::Best, InputCase::Average, InputCase::Worst];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            InputCase::Best    => "legjobb",
+            InputCase::Average => "atlagos",
+            InputCase::Worst   => "legrosszabb"
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Measurement {
+    pub counters: Counters,
+    pub cache_misses: u64,
+    pub weighted_cost: f64,
+    pub micros: u128
+}
+
+pub struct ReportRow {
+    pub algorithm: Algorithm,
+    pub case: InputCase,
+    pub size: usize,
+    pub measurement: Measurement,
+    pub stable: bool,
+    pub in_place: bool
+}
+
+impl ReportRow {
+    fn cells(&self) -> [String; 12] {
+        [
+            self.algorithm.name().to_string(),
+            self.case.name().to_string(),
+            self.size.to_string(),
+            self.measurement.counters.comparisons.to_string(),
+            self.measurement.counters.swaps.to_string(),
+            self.measurement.counters.writes.to_string(),
+            self.measurement.counters.reversals.to_string(),
+            self.measurement.cache_misses.to_string(),
+            format!("{:.1}", self.measurement.weighted_cost),
+            self.measurement.micros.to_string(),
+            yes_or_no(self.stable).to_string(),
+            yes_or_no(self.in_place).to_string()
+        ]
+    }
+}
+
+fn yes_or_no(flag: bool) -> &'static str {
+    if flag { "igen" } else { "nem" }
+}
+
+fn measure(algorithm: Algorithm, numbers: Vec<u64>, seed: u64, cost_model: &CostModel) -> Measurement {
+    let mut cache = CacheSimulator::default();
+    let mut array = SortArray::new(numbers, u64::cmp, sort_algorithms::seeded_rng(seed))
+                              .with_observer(|operation, _| cache.observe(operation));
+
+    let current_time = time::Instant::now();
+    algorithm.sort(&mut array);
+    let micros   = current_time.elapsed().as_micros();
+    let counters = array.counters();
+    drop(array);
+
+    Measurement {
+        counters,
+        cache_misses: cache.misses(),
+        weighted_cost: cost_model.weighted_cost(&counters, &cache),
+        micros
+    }
+}
+
+fn measure_case(algorithm: Algorithm, case: InputCase, size: usize, seed: u64, cost_model: &CostModel) -> Measurement {
+    match case {
+        InputCase::Best    => measure(algorithm, adversary::best_case_input(algorithm, size, seed), seed, cost_model),
+        InputCase::Worst   => measure(algorithm, adversary::worst_case_input(algorithm, size, seed), seed, cost_model),
+        InputCase::Average => {
+            let mut total = Measurement::default();
+
+            for trial in 0..AVERAGE_CASE_TRIALS {
+                let trial_seed = seed.wrapping_add(trial);
+                let mut numbers = Vec::new();
+                sort_algorithms::fill_vector_with_random_numbers(&mut numbers, size, &mut sort_algorithms::seeded_rng(trial_seed));
+
+                let measurement = measure(algorithm, numbers, trial_seed, cost_model);
+                total.counters.comparisons += measurement.counters.comparisons;
+                total.counters.swaps       += measurement.counters.swaps;
+                total.counters.writes      += measurement.counters.writes;
+                total.counters.reversals   += measurement.counters.reversals;
+                total.cache_misses         += measurement.cache_misses;
+                total.weighted_cost        += measurement.weighted_cost;
+                total.micros               += measurement.micros;
+            }
+
+            Measurement {
+                counters: Counters {
+                    comparisons: total.counters.comparisons / AVERAGE_CASE_TRIALS,
+                    swaps: total.counters.swaps / AVERAGE_CASE_TRIALS,
+                    writes: total.counters.writes / AVERAGE_CASE_TRIALS,
+                    reversals: total.counters.reversals / AVERAGE_CASE_TRIALS,
+                    ..Counters::default()
+                },
+                cache_misses: total.cache_misses / AVERAGE_CASE_TRIALS,
+                weighted_cost: total.weighted_cost / AVERAGE_CASE_TRIALS as f64,
+                micros: total.micros / AVERAGE_CASE_TRIALS as u128
+            }
+        }
+    }
+}
+
+pub fn is_stable(algorithm: Algorithm, seed: u64) -> bool {
+    (0..STABILITY_CHECK_TRIALS).all(|trial| {
+        let mut rng                   = sort_algorithms::seeded_rng(seed.wrapping_add(trial));
+        let mut keys: Vec<u64>        = (0..STABILITY_CHECK_SIZE as u64).map(|index| index % STABILITY_CHECK_KEYS).collect();
+        keys.shuffle(&mut rng);
+        let items: Vec<(u64, usize)>  = keys.into_iter()
+                                            .enumerate()
+                                            .map(|(index, key)| (key, index))
+                                            .collect();
+
+        let mut array = SortArray::new(items, |x_item: &(u64, usize), y_item: &(u64, usize)| x_item.0.cmp(&y_item.0), rng);
+        algorithm.sort(&mut array);
+
+        array.items()
+             .windows(2)
+             .all(|pair| pair[0].0 != pair[1].0 || pair[0].1 < pair[1].1)
+    })
+}
+
+pub fn generate_report(sizes: &[usize], seed: u64, cost_model: &CostModel) -> Vec<ReportRow> {
+    let mut rows = Vec::new();
+
+    for algorithm in Algorithm::ALL {
+        let stable = is_stable(algorithm, seed);
+
+        for size in sizes {
+            for case in InputCase::ALL {
+                rows.push(ReportRow {
+                    algorithm,
+                    case,
+                    size: *size,
+                    measurement: measure_case(algorithm, case, *size, seed, cost_model),
+                    stable,
+                    in_place: algorithm.is_in_place()
+                });
+            }
+        }
+    }
+
+    rows
+}
+
+pub fn to_markdown(rows: &[ReportRow], seed: u64, cost_model: &CostModel) -> String {
+    let mut markdown = format!("# Rendezesi algoritmusok osszehasonlitasa (seed: {})\n\n", seed);
+
+    markdown += &format!("Koltsegmodell sulyai: {}\n\n", cost_model.label());
+    markdown += &format!("Cache-modell: {} sor, soronkent {} elem, LRU csere\n\n", CACHE_LINES, CACHE_LINE_ITEMS);
+
+    markdown += &format!("| {} |\n", REPORT_HEADER.join(" | "));
+    markdown += &format!("|{}\n", "---|".repeat(REPORT_HEADER.len()));
+    for row in rows {
+        markdown += &format!("| {} |\n", row.cells().join(" | "));
+    }
+
+    markdown
+}
+
+pub fn to_csv(rows: &[ReportRow]) -> String {
+    let mut csv = format!("{}\n", REPORT_HEADER.join(","));
+
+    for row in rows {
+        let mut cells = row.cells();
+        cells[0] = format!("\"{}\"", cells[0]);
+        csv += &format!("{}\n", cells.join(","));
+    }
+
+    csv
+}
+
+pub fn save_markdown(rows: &[ReportRow], seed: u64, cost_model: &CostModel) -> io::Result<String> {
+    let file_name = format!("rendezesi_jelentes_{}.md", seed);
+    fs::write(&file_name, to_markdown(rows, seed, cost_model))?;
+    Ok(file_name)
+}
+
+pub fn save_csv(rows: &[ReportRow], seed: u64) -> io::Result<String> {
+    let file_name = format!("rendezesi_jelentes_{}.csv", seed);
+    fs::write(&file_name, to_csv(rows))?;
+    Ok(file_name)
+}
+
+fn report_screen<B: Backend>(frame: &mut Frame<B>, rows: &[ReportRow], state: &mut TableState, seed: u64, cost_model: &CostModel, message: &str) {
+    let report_layout = Layout::default()
+                               .direction(Direction::Vertical)
+                               .constraints(
+                                   [
+                                       Constraint::Min(5),
+                                       Constraint::Length(3)
+                                   ]
+                                   .as_ref(),
+                               )
+                               .split(frame.size());
+
+    let header = Row::new(REPORT_HEADER.iter().map(|title| Cell::from(*title)))
+                     .style(Style::default()
+                                  .fg(Color::Cyan)
+                                  .add_modifier(Modifier::BOLD));
+    let table_rows: Vec<Row> = rows.iter()
+                                   .map(|row| Row::new(row.cells()))
+                                   .collect();
+
+    let widths = [
+        Constraint::Length(36),
+        Constraint::Length(11),
+        Constraint::Length(6),
+        Constraint::Length(15),
+        Constraint::Length(10),
+        Constraint::Length(6),
+        Constraint::Length(8),
+        Constraint::Length(10),
+        Constraint::Length(17),
+        Constraint::Length(9),
+        Constraint::Length(6),
+        Constraint::Length(7)
+    ];
+
+    let table = Table::new(table_rows)
+                      .header(header)
+                      .widths(&widths)
+                      .style(Style::default()
+                                   .fg(Color::White))
+                      .highlight_style(Style::default()
+                                             .bg(Color::Cyan)
+                                             .fg(Color::Black)
+                                             .add_modifier(Modifier::BOLD))
+                      .block(Block::default()
+                                   .borders(Borders::ALL)
+                                   .border_type(BorderType::Rounded)
+                                   .border_style(Style::default()
+                                                       .fg(Color::Green))
+                                   .title(Span::styled(format!("Legjobb / atlagos / legrosszabb eset (seed: {}) | sulyok: {}", seed, cost_model.label()), Style::default()
+                                                                                                                                                                       .fg(Color::Cyan)
+                                                                                                                                                                       .add_modifier(Modifier::BOLD))));
+    frame.render_stateful_widget(table, report_layout[0], state);
+
+    let help_text = if message.is_empty() {
+        "Fel / Le / PgUp / PgDn: gorgetes | M: Markdown mentes | C: CSV mentes | ESC: vissza"
+    } else {
+        message
+    };
+    let help_paragraph = Paragraph::new(Span::from(help_text))
+                                   .style(Style::default()
+                                                .fg(Color::LightRed))
+                                   .block(Block::default()
+                                                .borders(Borders::ALL)
+                                                .border_type(BorderType::Rounded)
+                                                .border_style(Style::default()
+                                                                    .fg(Color::Green)));
+    frame.render_widget(help_paragraph, report_layout[1]);
+}
+
+fn save_message(result: io::Result<String>) -> String {
+    match result {
+        Ok(file_name)      => format!("Jelentes mentve: {}", file_name),
+        Err(error_message) => format!("Mentes sikertelen: {}", error_message)
+    }
+}
+
+pub fn run_report<B: Backend>(terminal: &mut Terminal<B>, seed: u64, cost_model: &CostModel) -> io::Result<()> {
+    terminal.draw(|frame| report_screen(frame, &[], &mut TableState::default(), seed, cost_model, "Jelentes keszitese..."))?;
+
+    let rows        = generate_report(&REPORT_SIZES, seed, cost_model);
+    let mut state   = TableState::default();
+    let mut message = String::new();
+    state.select(Some(0));
+
+    loop {
+        terminal.draw(|frame| report_screen(frame, &rows, &mut state, seed, cost_model, &message))?;
+
+        if let Event::Key(key) = event::read()? {
+            let selected = state.selected().unwrap_or(0);
+            match key.code {
+                KeyCode::Esc                                => return Ok(()),
+                KeyCode::Down                               => state.select(Some((selected + 1).min(rows.len() - 1))),
+                KeyCode::Up                                 => state.select(Some(selected.saturating_sub(1))),
+                KeyCode::PageDown                           => state.select(Some((selected + 20).min(rows.len() - 1))),
+                KeyCode::PageUp                             => state.select(Some(selected.saturating_sub(20))),
+                KeyCode::Char('m') | KeyCode::Char('M')     => message = save_message(save_markdown(&rows, seed, cost_model)),
+                KeyCode::Char('c') | KeyCode::Char('C')     => message = save_message(save_csv(&rows, seed)),
+                _ => {}
+            }
+        }
+    }
+}
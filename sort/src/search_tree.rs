@@ -0,0 +1,137 @@
+use std::cmp::Ordering;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TreeLink {
+    Root,
+    Left(usize),
+    Right(usize)
+}
+
+pub struct SearchTreeNode<T> {
+    pub value: T,
+    pub left: Option<usize>,
+    pub right: Option<usize>,
+    pub parent: Option<usize>,
+    pub depth: usize
+}
+
+pub struct SearchTree<T> {
+    nodes: Vec<SearchTreeNode<T>>
+}
+
+impl<T> Default for SearchTree<T> {
+    fn default() -> SearchTree<T> {
+        SearchTree::with_capacity(0)
+    }
+}
+
+impl<T> SearchTree<T> {
+    pub fn with_capacity(capacity: usize) -> SearchTree<T> {
+        SearchTree { nodes: Vec::with_capacity(capacity) }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn nodes(&self) -> &[SearchTreeNode<T>] {
+        &self.nodes
+    }
+
+    pub fn find_link<C>(&self, mut compare: C) -> TreeLink
+    where
+        C: FnMut(&T) -> Ordering
+    {
+        if self.nodes.is_empty() {
+            return TreeLink::Root;
+        }
+
+        let mut node = 0;
+        loop {
+            let (child, link) = if compare(&self.nodes[node].value) == Ordering::Less {
+                (self.nodes[node].left, TreeLink::Left(node))
+            } else {
+                (self.nodes[node].right, TreeLink::Right(node))
+            };
+
+            match child {
+                Some(next_node) => node = next_node,
+                None            => return link
+            }
+        }
+    }
+
+    pub fn attach(&mut self, value: T, link: TreeLink) -> usize {
+        let new_node = self.nodes.len();
+        let parent   = match link {
+            TreeLink::Root          => None,
+            TreeLink::Left(parent)  => {
+                self.nodes[parent].left = Some(new_node);
+                Some(parent)
+            }
+            TreeLink::Right(parent) => {
+                self.nodes[parent].right = Some(new_node);
+                Some(parent)
+            }
+        };
+        let depth = parent.map_or(0, |parent| self.nodes[parent].depth + 1);
+
+        self.nodes.push(SearchTreeNode { value, left: None, right: None, parent, depth });
+        new_node
+    }
+
+    pub fn path_to(&self, node: usize) -> Vec<usize> {
+        let mut path         = vec![node];
+        let mut current_node = node;
+        while let Some(parent) = self.nodes[current_node].parent {
+            path.push(parent);
+            current_node = parent;
+        }
+
+        path.reverse();
+        path
+    }
+
+    pub fn in_order(&self) -> Vec<usize> {
+        let mut order        = Vec::with_capacity(self.nodes.len());
+        let mut stack        = Vec::new();
+        let mut current_node = if self.nodes.is_empty() { None } else { Some(0) };
+        loop {
+            while let Some(node) = current_node {
+                stack.push(node);
+                current_node = self.nodes[node].left;
+            }
+
+            let Some(node) = stack.pop() else {
+                return order;
+            };
+            order.push(node);
+            current_node = self.nodes[node].right;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attached_values_come_back_in_order() {
+        let values   = [5, 2, 8, 2, 9, 1, 5];
+        let mut tree = SearchTree::with_capacity(values.len());
+        for value in values {
+            let link = tree.find_link(|node_value| value.cmp(node_value));
+            tree.attach(value, link);
+        }
+
+        let in_order: Vec<u64> = tree.in_order().into_iter().map(|node| tree.nodes()[node].value).collect();
+        assert_eq!(in_order, vec![1, 2, 2, 5, 5, 8, 9]);
+        assert_eq!(tree.path_to(6), vec![0, 2, 6]);
+        assert_eq!(tree.nodes()[6].depth, 2);
+        assert_eq!(tree.find_link(|_| Ordering::Less), TreeLink::Left(5));
+    }
+}
@@ -0,0 +1,81 @@
+use rand::{ Rng, seq::SliceRandom };
+use crate::{ adversary,
+             sort_algorithms::{ self, Algorithm, MAX_RANDOM_NUMBER },
+             sort_value::ChartValue };
+
+const SPECIAL_FLOATS: [f64; 6] = [f64::INFINITY, f64::NEG_INFINITY, f64::NAN, -f64::NAN, -0.0, 0.0];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataKind {
+    Unsigned,
+    Signed,
+    Float
+}
+
+impl DataKind {
+    pub const ALL: [DataKind; 3] = [DataKind::Unsigned, DataKind::Signed, DataKind::Float];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            DataKind::Unsigned => "nemnegativ egesz (u64)",
+            DataKind::Signed   => "elojeles egesz (i64)",
+            DataKind::Float    => "lebegopontos (f64)"
+        }
+    }
+
+    pub fn key(self) -> &'static str {
+        match self {
+            DataKind::Unsigned => "unsigned",
+            DataKind::Signed   => "signed",
+            DataKind::Float    => "float"
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<DataKind> {
+        DataKind::ALL.iter()
+                     .copied()
+                     .find(|data_kind| data_kind.key() == key)
+    }
+
+    pub fn next(self) -> DataKind {
+        let index = DataKind::ALL.iter()
+                                 .position(|data_kind| *data_kind == self)
+                                 .unwrap_or(0);
+        DataKind::ALL[(index + 1) % DataKind::ALL.len()]
+    }
+}
+
+pub fn generate_signed_values(size: usize, seed: u64) -> Vec<i64> {
+    let mut rng = sort_algorithms::seeded_rng(seed);
+    let limit   = MAX_RANDOM_NUMBER as i64;
+
+    (0..size).map(|_| rng.gen_range(-limit..limit))
+             .collect()
+}
+
+pub fn generate_float_values(size: usize, seed: u64) -> Vec<f64> {
+    let mut rng         = sort_algorithms::seeded_rng(seed);
+    let limit           = MAX_RANDOM_NUMBER as i64;
+    let with_specials   = size >= 2 * SPECIAL_FLOATS.len();
+    let mut values      = Vec::with_capacity(size);
+
+    for index in 0..size {
+        let value = if with_specials && index < SPECIAL_FLOATS.len() {
+            SPECIAL_FLOATS[index]
+        } else if index > 0 && rng.gen_ratio(1, 8) {
+            values[rng.gen_range(0..index)]
+        } else {
+            rng.gen_range(-limit..limit) as f64 / 10.0
+        };
+        values.push(value);
+    }
+    values.shuffle(&mut rng);
+
+    values
+}
+
+pub fn adversary_values<T: ChartValue>(algorithm: Algorithm, size: usize, seed: u64) -> Vec<T> {
+    adversary::worst_case_input(algorithm, size, seed).into_iter()
+                                                      .map(T::from_rank)
+                                                      .collect()
+}
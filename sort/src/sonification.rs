@@ -0,0 +1,121 @@
+use std::{ cmp::Ordering, f64::consts::PI, fs, io };
+use crate::{ signed_data::DataKind,
+             sort_algorithms::{ self, Algorithm },
+             sort_array::{ Operation, SortArray } };
+
+const SAMPLE_RATE: u32    = 44100;
+const TONE_MILLIS: u32    = 25;
+const MAX_TONES: usize    = 2400;
+const MIN_FREQUENCY: f64  = 120.0;
+const MAX_FREQUENCY: f64  = 1212.0;
+const TONE_AMPLITUDE: f64 = 0.3;
+
+struct Tone {
+    pitches: [f64; 2]
+}
+
+fn record_tones<T: Clone, C, P>(algorithm: Algorithm, items: Vec<T>, comparator: C, pitch: P, seed: u64) -> Vec<Tone>
+where
+    C: FnMut(&T, &T) -> Ordering,
+    P: Fn(&T) -> f64
+{
+    let mut tones      = Vec::with_capacity(MAX_TONES);
+    let mut stride     = 1;
+    let mut operations = 0;
+
+    let mut array = SortArray::new(items, comparator, sort_algorithms::seeded_rng(seed))
+                              .with_observer(|operation, items| {
+                                  let indices = match operation {
+                                      Operation::Compare(i_index, j_index) | Operation::Swap(i_index, j_index) => (i_index, j_index),
+                                      Operation::CompareValue(index) | Operation::Write(index)                 => (index, index),
+                                      Operation::Reverse(low, high)                                            => (low, high - 1),
+                                      _ => return
+                                  };
+                                  operations += 1;
+                                  if (operations - 1) % stride != 0 {
+                                      return;
+                                  }
+                                  if tones.len() == MAX_TONES {
+                                      let mut index = 0;
+                                      tones.retain(|_| {
+                                          index += 1;
+                                          index % 2 == 1
+                                      });
+                                      stride *= 2;
+                                      if (operations - 1) % stride != 0 {
+                                          return;
+                                      }
+                                  }
+                                  tones.push(Tone { pitches: [pitch(&items[indices.0]), pitch(&items[indices.1])] });
+                              });
+    algorithm.sort(&mut array);
+    drop(array);
+
+    tones
+}
+
+fn synthesize(tones: &[Tone]) -> Vec<i16> {
+    let finite_pitches = tones.iter().flat_map(|tone| tone.pitches).filter(|pitch| pitch.is_finite());
+    let (low, high)    = match finite_pitches.fold((f64::MAX, f64::MIN), |(low, high), pitch| (low.min(pitch), high.max(pitch))) {
+        (low, high) if low <= high => (low, high),
+        _                          => (0.0, 1.0)
+    };
+    let range          = (high - low).max(f64::EPSILON);
+
+    let samples_per_tone = (SAMPLE_RATE * TONE_MILLIS / 1000) as usize;
+    let mut samples      = Vec::with_capacity(tones.len() * samples_per_tone);
+
+    for tone in tones {
+        let frequencies = tone.pitches.map(|pitch| {
+            let pitch = if pitch.is_nan() { high } else { pitch.clamp(low, high) };
+            MIN_FREQUENCY + (MAX_FREQUENCY - MIN_FREQUENCY) * (pitch - low) / range
+        });
+
+        for sample in 0..samples_per_tone {
+            let progress = sample as f64 / samples_per_tone as f64;
+            let envelope = (progress / 0.1).min(1.0).min((1.0 - progress) / 0.3);
+            let time     = sample as f64 / SAMPLE_RATE as f64;
+            let value    = frequencies.iter()
+                                      .map(|frequency| (2.0 * PI * frequency * time).sin())
+                                      .sum::<f64>() / 2.0;
+            samples.push((value * envelope * TONE_AMPLITUDE * i16::MAX as f64) as i16);
+        }
+    }
+
+    samples
+}
+
+fn wav_bytes(samples: &[i16]) -> Vec<u8> {
+    let data_size = (samples.len() * 2) as u32;
+    let mut bytes = Vec::with_capacity(44 + data_size as usize);
+
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_size).to_le_bytes());
+    bytes.extend_from_slice(b"WAVE");
+    bytes.extend_from_slice(b"fmt ");
+    bytes.extend_from_slice(&16u32.to_le_bytes());
+    bytes.extend_from_slice(&1u16.to_le_bytes());
+    bytes.extend_from_slice(&1u16.to_le_bytes());
+    bytes.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    bytes.extend_from_slice(&(SAMPLE_RATE * 2).to_le_bytes());
+    bytes.extend_from_slice(&2u16.to_le_bytes());
+    bytes.extend_from_slice(&16u16.to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_size.to_le_bytes());
+    for sample in samples {
+        bytes.extend_from_slice(&sample.to_le_bytes());
+    }
+
+    bytes
+}
+
+pub fn export_wav<T: Clone, C, P>(algorithm: Algorithm, items: Vec<T>, comparator: C, pitch: P, data_kind: DataKind, seed: u64) -> io::Result<String>
+where
+    C: FnMut(&T, &T) -> Ordering,
+    P: Fn(&T) -> f64
+{
+    let file_name = format!("rendezes_hang_{}_{}_{}_{}.wav", algorithm.key(), data_kind.key(), items.len(), seed);
+    let tones     = record_tones(algorithm, items, comparator, pitch, seed);
+    fs::write(&file_name, wav_bytes(&synthesize(&tones)))?;
+    Ok(file_name)
+}
@@ -0,0 +1,169 @@
+use std::cmp::Ordering;
+use rand::rngs::StdRng;
+use crate::search_tree::TreeLink;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    Compare(usize, usize),
+    CompareValue(usize),
+    Swap(usize, usize),
+    Read(usize),
+    Write(usize),
+    Reverse(usize, usize),
+    CharRead(usize, usize),
+    SiftUp(usize),
+    SiftDown(usize, usize),
+    TreeInsert(usize, TreeLink),
+    ActiveRange(usize, usize),
+    Inserting(usize),
+    Merging(usize, usize, usize),
+    PivotPlaced(usize)
+}
+
+impl Operation {
+    pub fn changes_items(self) -> bool {
+        matches!(self, Operation::Swap(_, _) | Operation::Write(_) | Operation::Reverse(_, _))
+    }
+
+    pub fn is_marker(self) -> bool {
+        matches!(self, Operation::SiftUp(_) | Operation::SiftDown(_, _) | Operation::TreeInsert(_, _) |
+                       Operation::ActiveRange(_, _) | Operation::Inserting(_) | Operation::Merging(_, _, _) | Operation::PivotPlaced(_))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Counters {
+    pub comparisons: u64,
+    pub swaps: u64,
+    pub writes: u64,
+    pub reversals: u64,
+    pub character_reads: u64,
+    pub pointer_updates: u64
+}
+
+type Comparator<'a, T> = Box<dyn FnMut(&T, &T) -> Ordering + 'a>;
+type Observer<'a, T>   = Box<dyn FnMut(Operation, &[T]) + 'a>;
+
+pub struct SortArray<'a, T> {
+    items: Vec<T>,
+    comparator: Comparator<'a, T>,
+    observer: Option<Observer<'a, T>>,
+    counters: Counters,
+    rng: StdRng
+}
+
+impl<'a, T> SortArray<'a, T> {
+    pub fn new<C>(items: Vec<T>, comparator: C, rng: StdRng) -> SortArray<'a, T>
+    where
+        C: FnMut(&T, &T) -> Ordering + 'a
+    {
+        SortArray {
+            items,
+            comparator: Box::new(comparator),
+            observer: None,
+            counters: Counters::default(),
+            rng
+        }
+    }
+
+    pub fn with_observer<O>(mut self, observer: O) -> SortArray<'a, T>
+    where
+        O: FnMut(Operation, &[T]) + 'a
+    {
+        self.observer = Some(Box::new(observer));
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    pub fn counters(&self) -> Counters {
+        self.counters
+    }
+
+    pub fn rng(&mut self) -> &mut StdRng {
+        &mut self.rng
+    }
+
+    pub fn compare(&mut self, i_index: usize, j_index: usize) -> Ordering {
+        self.counters.comparisons += 1;
+        let ordering = (self.comparator)(&self.items[i_index], &self.items[j_index]);
+        self.notify(Operation::Compare(i_index, j_index));
+        ordering
+    }
+
+    pub fn read(&mut self, index: usize) -> T
+    where
+        T: Clone
+    {
+        self.notify(Operation::Read(index));
+        self.items[index].clone()
+    }
+
+    pub fn greater(&mut self, i_index: usize, j_index: usize) -> bool {
+        self.compare(i_index, j_index) == Ordering::Greater
+    }
+
+    pub fn less(&mut self, i_index: usize, j_index: usize) -> bool {
+        self.compare(i_index, j_index) == Ordering::Less
+    }
+
+    pub fn compare_value(&mut self, index: usize, value: &T) -> Ordering {
+        self.counters.comparisons += 1;
+        let ordering = (self.comparator)(&self.items[index], value);
+        self.notify(Operation::CompareValue(index));
+        ordering
+    }
+
+    pub fn swap(&mut self, i_index: usize, j_index: usize) {
+        self.counters.swaps += 1;
+        self.items.swap(i_index, j_index);
+        self.notify(Operation::Swap(i_index, j_index));
+    }
+
+    pub fn replace(&mut self, index: usize, value: T) -> T {
+        self.counters.writes += 1;
+        let previous_value = std::mem::replace(&mut self.items[index], value);
+        self.notify(Operation::Write(index));
+        previous_value
+    }
+
+    pub fn reverse(&mut self, low: usize, high: usize) {
+        self.counters.reversals += 1;
+        self.counters.swaps     += ((high - low) / 2) as u64;
+        self.items[low..high].reverse();
+        self.notify(Operation::Reverse(low, high));
+    }
+
+    pub fn mark(&mut self, operation: Operation) {
+        self.notify(operation);
+    }
+
+    fn notify(&mut self, operation: Operation) {
+        if let Some(observer) = self.observer.as_mut() {
+            observer(operation, &self.items);
+        }
+    }
+}
+
+impl<'a> SortArray<'a, String> {
+    pub fn char_at(&mut self, index: usize, depth: usize) -> Option<u8> {
+        self.counters.character_reads += 1;
+        let character = self.items[index].as_bytes().get(depth).copied();
+        self.notify(Operation::CharRead(index, depth));
+        character
+    }
+}
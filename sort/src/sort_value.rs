@@ -0,0 +1,105 @@
+use std::cmp::Ordering;
+use crate::sort_algorithms::MAX_RANDOM_NUMBER;
+
+const FLOAT_ORDER: &str = "-NaN < -inf < negativ < -0 < +0 < pozitiv < inf < NaN";
+
+pub trait SortValue: Clone {
+    fn compare(&self, other: &Self) -> Ordering;
+    fn label(&self) -> String;
+}
+
+pub trait ChartValue: SortValue {
+    const ORDER: Option<&'static str> = None;
+
+    fn plot_value(&self) -> f64;
+    fn bar_value(&self) -> Option<u64>;
+    fn from_rank(rank: u64) -> Self;
+}
+
+impl SortValue for u64 {
+    fn compare(&self, other: &u64) -> Ordering {
+        self.cmp(other)
+    }
+
+    fn label(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl SortValue for i64 {
+    fn compare(&self, other: &i64) -> Ordering {
+        self.cmp(other)
+    }
+
+    fn label(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl SortValue for f64 {
+    fn compare(&self, other: &f64) -> Ordering {
+        self.total_cmp(other)
+    }
+
+    fn label(&self) -> String {
+        match self {
+            value if value.is_nan() && value.is_sign_negative() => String::from("-NaN"),
+            value if value.is_nan()                             => String::from("NaN"),
+            value                                               => value.to_string()
+        }
+    }
+}
+
+impl SortValue for String {
+    fn compare(&self, other: &String) -> Ordering {
+        self.cmp(other)
+    }
+
+    fn label(&self) -> String {
+        self.clone()
+    }
+}
+
+impl ChartValue for u64 {
+    fn plot_value(&self) -> f64 {
+        *self as f64
+    }
+
+    fn bar_value(&self) -> Option<u64> {
+        Some(*self)
+    }
+
+    fn from_rank(rank: u64) -> u64 {
+        rank
+    }
+}
+
+impl ChartValue for i64 {
+    fn plot_value(&self) -> f64 {
+        *self as f64
+    }
+
+    fn bar_value(&self) -> Option<u64> {
+        None
+    }
+
+    fn from_rank(rank: u64) -> i64 {
+        rank as i64 - MAX_RANDOM_NUMBER as i64 / 2
+    }
+}
+
+impl ChartValue for f64 {
+    const ORDER: Option<&'static str> = Some(FLOAT_ORDER);
+
+    fn plot_value(&self) -> f64 {
+        *self
+    }
+
+    fn bar_value(&self) -> Option<u64> {
+        None
+    }
+
+    fn from_rank(rank: u64) -> f64 {
+        (rank as f64 - MAX_RANDOM_NUMBER as f64 / 2.0) / 10.0
+    }
+}
@@ -0,0 +1,301 @@
+use std::{ cell::Cell, cmp::Ordering };
+use crate::{ sort_array::Operation, sort_value::SortValue };
+
+pub struct Sortedness<T> {
+    shadow: Vec<T>,
+    sorted: Vec<T>,
+    initial_inversions: u64,
+    inversions: u64,
+    runs: usize,
+    longest_increasing_subsequence: Cell<Option<usize>>,
+    in_final_position: usize
+}
+
+impl<T: SortValue> Sortedness<T> {
+    pub fn new(items: &[T]) -> Sortedness<T> {
+        let mut sorted = items.to_vec();
+        sorted.sort_by(T::compare);
+
+        let inversions = count_inversions(items);
+
+        let mut sortedness = Sortedness {
+            shadow: items.to_vec(),
+            sorted,
+            initial_inversions: inversions,
+            inversions,
+            runs: 0,
+            longest_increasing_subsequence: Cell::new(None),
+            in_final_position: 0
+        };
+        sortedness.refresh(items);
+        sortedness
+    }
+
+    pub fn update(&mut self, operation: Operation, items: &[T]) {
+        match operation {
+            Operation::Swap(i_index, j_index) => self.apply_swap(i_index.min(j_index), i_index.max(j_index)),
+            Operation::Write(index)           => self.apply_write(index, items[index].clone()),
+            Operation::Reverse(_, _)          => self.refresh(items),
+            _ => {}
+        }
+    }
+
+    pub fn refresh(&mut self, items: &[T]) {
+        self.shadow.clone_from_slice(items);
+        self.inversions        = count_inversions(items);
+        self.runs              = self.count_runs();
+        self.in_final_position = (0..items.len()).filter(|index| self.is_in_final_position(*index))
+                                                 .count();
+        self.longest_increasing_subsequence.set(None);
+    }
+
+    fn apply_write(&mut self, index: usize, value: T) {
+        let previous_value = &self.shadow[index];
+
+        let mut delta: i64 = 0;
+        for before_value in &self.shadow[..index] {
+            delta += greater(before_value, &value) as i64 - greater(before_value, previous_value) as i64;
+        }
+        for after_value in &self.shadow[index + 1..] {
+            delta += greater(&value, after_value) as i64 - greater(previous_value, after_value) as i64;
+        }
+        self.inversions = (self.inversions as i64 + delta) as u64;
+
+        let boundaries: Vec<usize> = [index.checked_sub(1), Some(index)].into_iter()
+                                                                       .flatten()
+                                                                       .filter(|boundary| boundary + 1 < self.shadow.len())
+                                                                       .collect();
+
+        let descents_before = boundaries.iter().filter(|boundary| self.is_descent(**boundary)).count();
+        let final_before    = self.is_in_final_position(index) as usize;
+
+        self.shadow[index] = value;
+
+        let descents_after = boundaries.iter().filter(|boundary| self.is_descent(**boundary)).count();
+        let final_after    = self.is_in_final_position(index) as usize;
+
+        self.runs              = self.runs + descents_after - descents_before;
+        self.in_final_position = self.in_final_position + final_after - final_before;
+        self.longest_increasing_subsequence.set(None);
+    }
+
+    fn apply_swap(&mut self, low: usize, high: usize) {
+        if low == high {
+            return;
+        }
+
+        let low_value  = &self.shadow[low];
+        let high_value = &self.shadow[high];
+
+        let mut delta: i64 = greater(high_value, low_value) as i64 - greater(low_value, high_value) as i64;
+        for middle_value in &self.shadow[low + 1..high] {
+            delta += greater(high_value, middle_value) as i64 + greater(middle_value, low_value) as i64
+                   - greater(low_value, middle_value) as i64 - greater(middle_value, high_value) as i64;
+        }
+        self.inversions = (self.inversions as i64 + delta) as u64;
+
+        let mut boundaries: Vec<usize> = [low.checked_sub(1), Some(low), Some(high - 1), Some(high)].into_iter()
+                                                                                                   .flatten()
+                                                                                                   .filter(|boundary| boundary + 1 < self.shadow.len())
+                                                                                                   .collect();
+        boundaries.dedup();
+
+        let descents_before = boundaries.iter().filter(|boundary| self.is_descent(**boundary)).count();
+        let final_before    = self.is_in_final_position(low) as usize + self.is_in_final_position(high) as usize;
+
+        self.shadow.swap(low, high);
+
+        let descents_after = boundaries.iter().filter(|boundary| self.is_descent(**boundary)).count();
+        let final_after    = self.is_in_final_position(low) as usize + self.is_in_final_position(high) as usize;
+
+        self.runs              = self.runs + descents_after - descents_before;
+        self.in_final_position = self.in_final_position + final_after - final_before;
+        self.longest_increasing_subsequence.set(None);
+    }
+
+    fn is_descent(&self, index: usize) -> bool {
+        greater(&self.shadow[index], &self.shadow[index + 1])
+    }
+
+    fn is_in_final_position(&self, index: usize) -> bool {
+        self.shadow[index].compare(&self.sorted[index]) == Ordering::Equal
+    }
+
+    fn count_runs(&self) -> usize {
+        if self.shadow.is_empty() {
+            return 0;
+        }
+
+        1 + (0..self.shadow.len() - 1).filter(|index| self.is_descent(*index))
+                                      .count()
+    }
+
+    pub fn len(&self) -> usize {
+        self.shadow.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shadow.is_empty()
+    }
+
+    pub fn inversions(&self) -> u64 {
+        self.inversions
+    }
+
+    pub fn runs(&self) -> usize {
+        self.runs
+    }
+
+    pub fn longest_increasing_subsequence(&self) -> usize {
+        match self.longest_increasing_subsequence.get() {
+            Some(length) => length,
+            None         => {
+                let length = longest_increasing_subsequence(&self.shadow);
+                self.longest_increasing_subsequence.set(Some(length));
+                length
+            }
+        }
+    }
+
+    pub fn in_final_position(&self) -> usize {
+        self.in_final_position
+    }
+
+    pub fn progress(&self) -> f64 {
+        if self.initial_inversions == 0 {
+            1.0
+        } else {
+            1.0 - self.inversions as f64 / self.initial_inversions as f64
+        }
+    }
+}
+
+fn greater<T: SortValue>(left: &T, right: &T) -> bool {
+    left.compare(right) == Ordering::Greater
+}
+
+fn count_inversions<T: SortValue>(items: &[T]) -> u64 {
+    let mut buffer = items.to_vec();
+    merge_count(&mut buffer)
+}
+
+fn merge_count<T: SortValue>(items: &mut [T]) -> u64 {
+    if items.len() < 2 {
+        return 0;
+    }
+
+    let middle         = items.len() / 2;
+    let mut inversions = merge_count(&mut items[..middle]) + merge_count(&mut items[middle..]);
+
+    let mut merged = Vec::with_capacity(items.len());
+    let (mut left_index, mut right_index) = (0, middle);
+    while left_index < middle && right_index < items.len() {
+        if greater(&items[left_index], &items[right_index]) {
+            inversions += (middle - left_index) as u64;
+            merged.push(items[right_index].clone());
+            right_index += 1;
+        } else {
+            merged.push(items[left_index].clone());
+            left_index += 1;
+        }
+    }
+    merged.extend_from_slice(&items[left_index..middle]);
+    merged.extend_from_slice(&items[right_index..]);
+    items.clone_from_slice(&merged);
+
+    inversions
+}
+
+fn longest_increasing_subsequence<T: SortValue>(items: &[T]) -> usize {
+    let mut tails: Vec<T> = Vec::new();
+
+    for item in items {
+        let position = tails.partition_point(|tail| !greater(tail, item));
+        if position == tails.len() {
+            tails.push(item.clone());
+        } else {
+            tails[position] = item.clone();
+        }
+    }
+
+    tails.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+    use super::*;
+    use crate::sort_algorithms::seeded_rng;
+
+    fn brute_force_inversions(items: &[u64]) -> u64 {
+        (0..items.len()).map(|i_index| (i_index + 1..items.len()).filter(|j_index| items[i_index] > items[*j_index]).count() as u64)
+                        .sum()
+    }
+
+    fn brute_force_longest_increasing_subsequence(items: &[u64]) -> usize {
+        let mut lengths = vec![1; items.len()];
+        for j_index in 0..items.len() {
+            for i_index in 0..j_index {
+                if items[i_index] <= items[j_index] {
+                    lengths[j_index] = lengths[j_index].max(lengths[i_index] + 1);
+                }
+            }
+        }
+
+        lengths.into_iter().max().unwrap_or(0)
+    }
+
+    fn assert_matches_brute_force(sortedness: &Sortedness<u64>, items: &[u64], sorted: &[u64]) {
+        assert_eq!(sortedness.inversions(), brute_force_inversions(items), "{:?}", items);
+        assert_eq!(sortedness.runs(), items.windows(2).filter(|pair| pair[0] > pair[1]).count() + !items.is_empty() as usize, "{:?}", items);
+        assert_eq!(sortedness.longest_increasing_subsequence(), brute_force_longest_increasing_subsequence(items), "{:?}", items);
+        assert_eq!(sortedness.in_final_position(), (0..items.len()).filter(|index| items[*index] == sorted[*index]).count(), "{:?}", items);
+    }
+
+    #[test]
+    fn incremental_updates_match_a_full_recount() {
+        let mut rng = seeded_rng(11);
+        for size in [1, 2, 3, 8, 40] {
+            let mut items: Vec<u64> = (0..size).map(|_| rng.gen_range(0..size as u64)).collect();
+            let mut sortedness      = Sortedness::new(&items);
+            let mut sorted          = items.clone();
+            sorted.sort();
+            assert_matches_brute_force(&sortedness, &items, &sorted);
+
+            for _ in 0..200 {
+                let (i_index, j_index) = (rng.gen_range(0..size), rng.gen_range(0..size));
+                let operation = match rng.gen_range(0..3) {
+                    0 => {
+                        items.swap(i_index, j_index);
+                        Operation::Swap(i_index, j_index)
+                    }
+                    1 => {
+                        items[i_index] = items[j_index];
+                        Operation::Write(i_index)
+                    }
+                    _ => {
+                        let (low, high) = (i_index.min(j_index), i_index.max(j_index) + 1);
+                        items[low..high].reverse();
+                        Operation::Reverse(low, high)
+                    }
+                };
+
+                sortedness.update(operation, &items);
+                assert_matches_brute_force(&sortedness, &items, &sorted);
+            }
+        }
+    }
+
+    #[test]
+    fn progress_runs_from_zero_to_one() {
+        let mut items      = vec![3u64, 2, 1];
+        let mut sortedness = Sortedness::new(&items);
+        assert_eq!(sortedness.progress(), 0.0);
+
+        items.swap(0, 2);
+        sortedness.update(Operation::Swap(0, 2), &items);
+        assert_eq!(sortedness.inversions(), 0);
+        assert_eq!(sortedness.progress(), 1.0);
+        assert_eq!(Sortedness::<u64>::new(&[]).progress(), 1.0);
+    }
+}
@@ -0,0 +1,350 @@
+use std::{ fs::{ self, OpenOptions }, io::{ self, Write } };
+use crossterm::event::{ self, Event, KeyCode };
+use rand::seq::index;
+use tui::{ backend::Backend,
+           layout::{ Layout, Direction, Constraint },
+           style::{ Color, Style, Modifier },
+           widgets::{ Block, Borders, BorderType, BarChart, Paragraph, Wrap },
+           text::{ Span, Spans },
+           Frame,
+           Terminal };
+use crate::{ prompt,
+             sort_algorithms::{ self, list_type::StatefulList, Algorithm, MAX_RANDOM_NUMBER },
+             sort_array::SortArray };
+
+const GAME_SIZE: usize         = 10;
+const HIGHSCORE_FILE: &str     = "rendezo_jatek_rekordok.txt";
+const HIGHSCORES_SHOWN: usize  = 5;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SwapMode {
+    AnyTwo,
+    Adjacent
+}
+
+impl SwapMode {
+    fn name(self) -> &'static str {
+        match self {
+            SwapMode::AnyTwo   => "tetszoleges ket elem csereje",
+            SwapMode::Adjacent => "csak szomszedos csere"
+        }
+    }
+
+    fn key(self) -> &'static str {
+        match self {
+            SwapMode::AnyTwo   => "any",
+            SwapMode::Adjacent => "adjacent"
+        }
+    }
+}
+
+struct Highscore {
+    mode_key: String,
+    name: String,
+    moves: usize,
+    minimum: usize,
+    seed: u64
+}
+
+impl Highscore {
+    fn parse(line: &str) -> Option<Highscore> {
+        let fields: Vec<&str> = line.split(';').collect();
+        match fields.as_slice() {
+            [mode_key, name, moves, minimum, seed] => Some(Highscore {
+                mode_key: mode_key.to_string(),
+                name: name.to_string(),
+                moves: moves.parse().ok()?,
+                minimum: minimum.parse().ok()?,
+                seed: seed.parse().ok()?
+            }),
+            _ => None
+        }
+    }
+
+    fn to_line(&self) -> String {
+        format!("{};{};{};{};{}", self.mode_key, self.name, self.moves, self.minimum, self.seed)
+    }
+
+    fn extra_moves(&self) -> usize {
+        self.moves.saturating_sub(self.minimum)
+    }
+}
+
+fn load_highscores() -> Vec<Highscore> {
+    fs::read_to_string(HIGHSCORE_FILE).map(|content| content.lines()
+                                                           .filter_map(Highscore::parse)
+                                                           .collect())
+                                      .unwrap_or_default()
+}
+
+fn save_highscore(highscore: &Highscore) -> io::Result<()> {
+    let mut highscore_file = OpenOptions::new().create(true)
+                                               .append(true)
+                                               .open(HIGHSCORE_FILE)?;
+    highscore_file.write_all((highscore.to_line() + "\n").as_bytes())
+}
+
+fn best_highscores(highscores: &[Highscore], mode: SwapMode) -> Vec<&Highscore> {
+    let mut best: Vec<&Highscore> = highscores.iter()
+                                              .filter(|highscore| highscore.mode_key == mode.key())
+                                              .collect();
+    best.sort_by_key(|highscore| (highscore.extra_moves(), highscore.moves));
+    best.truncate(HIGHSCORES_SHOWN);
+    best
+}
+
+fn count_inversions(numbers: &[u64]) -> usize {
+    (0..numbers.len()).map(|i_index| numbers[i_index + 1..].iter()
+                                                           .filter(|number| **number < numbers[i_index])
+                                                           .count())
+                      .sum()
+}
+
+fn minimum_swaps(numbers: &[u64]) -> usize {
+    let mut order: Vec<usize> = (0..numbers.len()).collect();
+    order.sort_by_key(|index| numbers[*index]);
+
+    let mut visited = vec![false; numbers.len()];
+    let mut cycles  = 0;
+    for start in 0..numbers.len() {
+        if !visited[start] {
+            cycles += 1;
+            let mut position = start;
+            while !visited[position] {
+                visited[position] = true;
+                position = order[position];
+            }
+        }
+    }
+
+    numbers.len() - cycles
+}
+
+fn algorithm_swaps(algorithm: Algorithm, numbers: &[u64], seed: u64) -> u64 {
+    let mut array = SortArray::new(numbers.to_vec(), u64::cmp, sort_algorithms::seeded_rng(seed));
+    algorithm.sort(&mut array);
+    array.counters().swaps
+}
+
+struct SortingGame {
+    numbers: StatefulList<u64>,
+    initial_numbers: Vec<u64>,
+    mode: SwapMode,
+    marked: Option<usize>,
+    moves: usize,
+    seed: u64,
+    highscores: Vec<Highscore>,
+    message: String
+}
+
+impl SortingGame {
+    fn new(seed: u64, mode: SwapMode) -> SortingGame {
+        let mut rng                  = sort_algorithms::seeded_rng(seed);
+        let initial_numbers: Vec<u64> = index::sample(&mut rng, MAX_RANDOM_NUMBER as usize - 1, GAME_SIZE).into_iter()
+                                                                                                         .map(|value| value as u64 + 1)
+                                                                                                         .collect();
+
+        let mut numbers = StatefulList::with_items(initial_numbers.clone());
+        numbers.state.select(Some(0));
+
+        SortingGame {
+            numbers,
+            initial_numbers,
+            mode,
+            marked: None,
+            moves: 0,
+            seed,
+            highscores: load_highscores(),
+            message: String::new()
+        }
+    }
+
+    fn is_sorted(&self) -> bool {
+        self.numbers.items.windows(2).all(|pair| pair[0] <= pair[1])
+    }
+
+    fn minimum_moves(&self) -> usize {
+        match self.mode {
+            SwapMode::AnyTwo   => minimum_swaps(&self.initial_numbers),
+            SwapMode::Adjacent => count_inversions(&self.initial_numbers)
+        }
+    }
+
+    fn swap(&mut self, i_index: usize, j_index: usize) {
+        self.numbers.items.swap(i_index, j_index);
+        self.moves += 1;
+    }
+
+    fn select(&mut self) {
+        if self.is_sorted() {
+            return;
+        }
+
+        let selected = self.numbers.state.selected().unwrap_or(0);
+        match self.mode {
+            SwapMode::Adjacent => if selected + 1 < self.numbers.items.len() {
+                self.swap(selected, selected + 1);
+            }
+            SwapMode::AnyTwo   => match self.marked.take() {
+                Some(marked) if marked != selected => self.swap(marked, selected),
+                Some(_)                            => {}
+                None                               => self.marked = Some(selected)
+            }
+        }
+    }
+
+    fn labels(&self) -> Vec<String> {
+        let selected = self.numbers.state.selected();
+
+        (0..self.numbers.items.len()).map(|index| match (Some(index) == self.marked, Some(index) == selected) {
+                                         (true, true)   => String::from("*^"),
+                                         (true, false)  => String::from("*"),
+                                         (false, true)  => String::from("^"),
+                                         (false, false) => String::new()
+                                     })
+                                     .collect()
+    }
+}
+
+fn sorting_game_screen<B: Backend>(frame: &mut Frame<B>, game: &SortingGame) {
+    let game_layout = Layout::default()
+                             .direction(Direction::Horizontal)
+                             .constraints(
+                                 [
+                                     Constraint::Min(0),
+                                     Constraint::Length(50)
+                                 ]
+                                 .as_ref(),
+                             )
+                             .split(frame.size());
+
+    let labels                       = game.labels();
+    let bar_data: Vec<(&str, u64)>   = labels.iter()
+                                             .zip(&game.numbers.items)
+                                             .map(|(label, number)| (label.as_str(), *number))
+                                             .collect();
+    let bar_width = (game_layout[0].width.saturating_sub(2) as usize / GAME_SIZE)
+                        .saturating_sub(1)
+                        .clamp(1, 8) as u16;
+
+    let game_chart = BarChart::default()
+                              .block(Block::default()
+                                           .title(Span::styled(format!("Rendezo jatek ({})", game.mode.name()), Style::default()
+                                                                                                                     .fg(Color::Cyan)
+                                                                                                                     .add_modifier(Modifier::BOLD)))
+                                           .borders(Borders::ALL)
+                                           .border_type(BorderType::Rounded)
+                                           .style(Style::default()
+                                                        .fg(Color::LightGreen)))
+                              .data(&bar_data)
+                              .bar_width(bar_width)
+                              .bar_style(Style::default()
+                                               .fg(Color::LightGreen))
+                              .label_style(Style::default()
+                                                 .fg(Color::Yellow)
+                                                 .add_modifier(Modifier::BOLD))
+                              .value_style(Style::default()
+                                                 .fg(Color::Black)
+                                                 .bg(Color::LightGreen));
+    frame.render_widget(game_chart, game_layout[0]);
+
+    let mut info_lines = vec![
+        Spans::from(format!("Seed: {}", game.seed)),
+        Spans::from(format!("Lepesek: {}", game.moves)),
+        Spans::from(format!("Minimalis lepesszam: {}", game.minimum_moves())),
+        Spans::from(format!("Buborekos rendezes cserei: {}", algorithm_swaps(Algorithm::BubbleSort, &game.initial_numbers, game.seed))),
+        Spans::from(format!("Minimum kivalasztasos rendezes cserei: {}", algorithm_swaps(Algorithm::MinSort, &game.initial_numbers, game.seed))),
+        Spans::from(""),
+        Spans::from(Span::styled("Rekordok:", Style::default()
+                                                    .fg(Color::Cyan)
+                                                    .add_modifier(Modifier::BOLD)))
+    ];
+    info_lines.extend(best_highscores(&game.highscores, game.mode).into_iter()
+                                                                  .map(|highscore| Spans::from(format!("{}: {} lepes (minimum {}, seed {})",
+                                                                                                       highscore.name, highscore.moves, highscore.minimum, highscore.seed))));
+    info_lines.extend([
+        Spans::from(""),
+        Spans::from("Bal / Jobb: kurzor mozgatasa"),
+        Spans::from(match game.mode {
+            SwapMode::AnyTwo   => "Szokoz / Enter: elem kijelolese, majd csere",
+            SwapMode::Adjacent => "Szokoz / Enter: csere a jobb szomszeddal"
+        }),
+        Spans::from("M: csere mod valtasa | N: uj jatek | ESC: vissza"),
+        Spans::from(""),
+        Spans::from(Span::styled(game.message.as_str(), Style::default()
+                                                              .fg(Color::LightRed)))
+    ]);
+
+    let info_paragraph = Paragraph::new(info_lines)
+                                   .style(Style::default()
+                                                .fg(Color::White))
+                                   .wrap(Wrap { trim: true })
+                                   .block(Block::default()
+                                                .title(Span::styled("Allas", Style::default()
+                                                                                   .fg(Color::Cyan)
+                                                                                   .add_modifier(Modifier::BOLD)))
+                                                .borders(Borders::ALL)
+                                                .border_type(BorderType::Rounded)
+                                                .style(Style::default()
+                                                             .fg(Color::LightGreen)));
+    frame.render_widget(info_paragraph, game_layout[1]);
+}
+
+fn finish_game<B: Backend>(terminal: &mut Terminal<B>, game: &mut SortingGame) -> io::Result<()> {
+    let name = prompt::read_value(terminal, &format!("Kesz {} lepesben! Neved a rekordlistahoz (ESC: kihagyas)", game.moves), "", |input| {
+        let name = input.trim();
+        if name.is_empty() || name.contains(';') {
+            Err(String::from("A nev nem lehet ures es nem tartalmazhat ';' karaktert!"))
+        } else {
+            Ok(name.to_string())
+        }
+    })?;
+
+    game.message = format!("Rendezve {} lepesben (minimum: {}).", game.moves, game.minimum_moves());
+
+    if let Some(name) = name {
+        let highscore = Highscore {
+            mode_key: game.mode.key().to_string(),
+            name,
+            moves: game.moves,
+            minimum: game.minimum_moves(),
+            seed: game.seed
+        };
+
+        match save_highscore(&highscore) {
+            Ok(())             => game.highscores.push(highscore),
+            Err(error_message) => game.message = format!("Rekord mentese sikertelen: {}", error_message)
+        }
+    }
+
+    Ok(())
+}
+
+pub fn run_sorting_game<B: Backend>(terminal: &mut Terminal<B>, seed: u64) -> io::Result<()> {
+    let mut game = SortingGame::new(seed, SwapMode::AnyTwo);
+
+    loop {
+        terminal.draw(|frame| sorting_game_screen(frame, &game))?;
+
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Esc                            => return Ok(()),
+                KeyCode::Left                           => game.numbers.previous(),
+                KeyCode::Right                          => game.numbers.next(),
+                KeyCode::Char(' ') | KeyCode::Enter     => {
+                    let was_sorted = game.is_sorted();
+                    game.select();
+                    if !was_sorted && game.is_sorted() {
+                        finish_game(terminal, &mut game)?;
+                    }
+                }
+                KeyCode::Char('m') | KeyCode::Char('M') => {
+                    let mode = if game.mode == SwapMode::AnyTwo { SwapMode::Adjacent } else { SwapMode::AnyTwo };
+                    game     = SortingGame::new(game.seed, mode);
+                }
+                KeyCode::Char('n') | KeyCode::Char('N') => game = SortingGame::new(sort_algorithms::random_seed(), game.mode),
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,384 @@
+use std::{ io, time };
+use crossterm::event::{ self, Event };
+use rand::seq::SliceRandom;
+use tui::{ backend::Backend,
+           layout::{ Layout, Direction, Constraint, Alignment },
+           style::{ Color, Style, Modifier },
+           widgets::{ Block, Borders, BorderType, Paragraph },
+           text::{ Span, Spans },
+           Frame,
+           Terminal };
+use crate::{ narration::{ Narrator, NARRATION_HEIGHT },
+             sort_algorithms::{ self, FRAME_INTERVAL_MILLIS, MAX_VECTOR_SIZE },
+             sort_array::{ Counters, Operation, SortArray } };
+
+const BURST_THRESHOLD: usize = 8;
+const ALPHABET_SIZE: usize   = 256;
+const END_OF_STRING: &str    = "·";
+
+pub const WORD_LIST: [&str; 40] = [
+    "src/main.rs", "src/lib.rs", "src/sort_array.rs", "src/sortedness.rs", "src/adversary.rs",
+    "src/array_editor.rs", "src/prompt.rs", "src/report.rs", "Cargo.toml", "Cargo.lock",
+    "README.md", "sort_algorithms", "sort_array", "sortedness", "sorted",
+    "sort", "swap", "swaps", "switch", "string",
+    "stable", "stack", "state", "static", "statement",
+    "bubble_sort", "bucket", "buffer", "burst", "burstsort",
+    "min_sort", "minimum", "merge", "median", "memory",
+    "quick_sort", "queue", "quicksort", "insert_sort", "index"
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StringAlgorithm {
+    MsdRadixSort,
+    ThreeWayRadixQuickSort,
+    BurstSort
+}
+
+impl StringAlgorithm {
+    pub const ALL: [StringAlgorithm; 3] = [
+        StringAlgorithm::MsdRadixSort,
+        StringAlgorithm::ThreeWayRadixQuickSort,
+        StringAlgorithm::BurstSort
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            StringAlgorithm::MsdRadixSort           => "MSD radix rendezes (szovegek)",
+            StringAlgorithm::ThreeWayRadixQuickSort => "Haromutas radix gyorsrendezes (szovegek)",
+            StringAlgorithm::BurstSort              => "Burstsort (szovegek)"
+        }
+    }
+
+    pub fn key(self) -> &'static str {
+        match self {
+            StringAlgorithm::MsdRadixSort           => "msd_radix",
+            StringAlgorithm::ThreeWayRadixQuickSort => "three_way_radix_quick",
+            StringAlgorithm::BurstSort              => "burst"
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<StringAlgorithm> {
+        StringAlgorithm::ALL.iter()
+                            .copied()
+                            .find(|algorithm| algorithm.key() == key)
+    }
+
+    pub fn sort(self, array: &mut SortArray<String>) {
+        match self {
+            StringAlgorithm::MsdRadixSort           => msd_radix_sort(array),
+            StringAlgorithm::ThreeWayRadixQuickSort => three_way_radix_quick_sort(array),
+            StringAlgorithm::BurstSort              => burst_sort(array)
+        }
+    }
+}
+
+pub fn prepare_words(input_words: &[String], seed: u64) -> Vec<String> {
+    if input_words.is_empty() {
+        let mut words: Vec<String> = WORD_LIST.iter()
+                                              .map(|word| word.to_string())
+                                              .collect();
+        words.shuffle(&mut sort_algorithms::seeded_rng(seed));
+        words
+    } else {
+        input_words.to_vec()
+    }
+}
+
+fn bucket_of(character: Option<u8>) -> usize {
+    character.map_or(0, |character| character as usize + 1)
+}
+
+pub fn msd_radix_sort(array: &mut SortArray<String>) {
+    msd_radix_sort_range(array, 0, array.len(), 0);
+}
+
+fn msd_radix_sort_range(array: &mut SortArray<String>, low: usize, high: usize, depth: usize) {
+    if high - low < 2 {
+        return;
+    }
+
+    let buckets: Vec<usize> = (low..high).map(|index| bucket_of(array.char_at(index, depth)))
+                                         .collect();
+
+    let mut bucket_starts = vec![0; ALPHABET_SIZE + 2];
+    for bucket in &buckets {
+        bucket_starts[bucket + 1] += 1;
+    }
+    for bucket in 0..=ALPHABET_SIZE {
+        bucket_starts[bucket + 1] += bucket_starts[bucket];
+    }
+
+    let mut sorted_items = vec![String::new(); high - low];
+    let mut next_slot    = bucket_starts.clone();
+    for (offset, bucket) in buckets.iter().enumerate() {
+        sorted_items[next_slot[*bucket]] = array.read(low + offset);
+        next_slot[*bucket] += 1;
+    }
+    for (offset, item) in sorted_items.into_iter().enumerate() {
+        array.replace(low + offset, item);
+    }
+
+    for bucket in 1..=ALPHABET_SIZE {
+        msd_radix_sort_range(array, low + bucket_starts[bucket], low + bucket_starts[bucket + 1], depth + 1);
+    }
+}
+
+pub fn three_way_radix_quick_sort(array: &mut SortArray<String>) {
+    three_way_radix_quick_sort_range(array, 0, array.len(), 0);
+}
+
+fn three_way_radix_quick_sort_range(array: &mut SortArray<String>, low: usize, high: usize, depth: usize) {
+    if high - low < 2 {
+        return;
+    }
+
+    let pivot_character   = array.char_at(low, depth);
+    let mut less_end      = low;
+    let mut greater_start = high;
+    let mut index         = low + 1;
+    while index < greater_start {
+        let character = array.char_at(index, depth);
+        if character < pivot_character {
+            array.swap(less_end, index);
+            less_end += 1;
+            index    += 1;
+        } else if character > pivot_character {
+            greater_start -= 1;
+            array.swap(index, greater_start);
+        } else {
+            index += 1;
+        }
+    }
+
+    three_way_radix_quick_sort_range(array, low, less_end, depth);
+    if pivot_character.is_some() {
+        three_way_radix_quick_sort_range(array, less_end, greater_start, depth + 1);
+    }
+    three_way_radix_quick_sort_range(array, greater_start, high, depth);
+}
+
+enum BurstSlot {
+    Bucket(Vec<usize>),
+    Trie(usize)
+}
+
+struct BurstTrieNode {
+    finished: Vec<usize>,
+    slots: Vec<BurstSlot>
+}
+
+impl BurstTrieNode {
+    fn new() -> BurstTrieNode {
+        BurstTrieNode {
+            finished: Vec::new(),
+            slots: (0..ALPHABET_SIZE).map(|_| BurstSlot::Bucket(Vec::new())).collect()
+        }
+    }
+}
+
+pub fn burst_sort(array: &mut SortArray<String>) {
+    let mut nodes = vec![BurstTrieNode::new()];
+
+    for index in 0..array.len() {
+        burst_insert(array, &mut nodes, 0, index, 0);
+    }
+
+    let mut order = Vec::with_capacity(array.len());
+    burst_collect(array, &nodes, 0, 0, &mut order);
+
+    let sorted_items: Vec<String> = order.into_iter()
+                                         .map(|index| array.read(index))
+                                         .collect();
+    for (position, item) in sorted_items.into_iter().enumerate() {
+        array.replace(position, item);
+    }
+}
+
+fn burst_insert(array: &mut SortArray<String>, nodes: &mut Vec<BurstTrieNode>, mut node: usize, index: usize, mut depth: usize) {
+    loop {
+        let Some(character) = array.char_at(index, depth) else {
+            nodes[node].finished.push(index);
+            return;
+        };
+
+        match &mut nodes[node].slots[character as usize] {
+            BurstSlot::Trie(child) => {
+                node   = *child;
+                depth += 1;
+            }
+            BurstSlot::Bucket(bucket) => {
+                bucket.push(index);
+                if bucket.len() > BURST_THRESHOLD {
+                    let bucket = std::mem::take(bucket);
+                    let child  = nodes.len();
+                    nodes.push(BurstTrieNode::new());
+                    nodes[node].slots[character as usize] = BurstSlot::Trie(child);
+
+                    for bucket_index in bucket {
+                        burst_insert(array, nodes, child, bucket_index, depth + 1);
+                    }
+                }
+                return;
+            }
+        }
+    }
+}
+
+fn burst_collect(array: &mut SortArray<String>, nodes: &[BurstTrieNode], node: usize, depth: usize, order: &mut Vec<usize>) {
+    order.extend_from_slice(&nodes[node].finished);
+
+    for slot in &nodes[node].slots {
+        match slot {
+            BurstSlot::Trie(child)    => burst_collect(array, nodes, *child, depth + 1, order),
+            BurstSlot::Bucket(bucket) => {
+                let bucket_start = order.len();
+                for index in bucket {
+                    order.push(*index);
+                    let mut position = order.len() - 1;
+                    while position > bucket_start && less_from(array, order[position], order[position - 1], depth + 1) {
+                        order.swap(position, position - 1);
+                        position -= 1;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn less_from(array: &mut SortArray<String>, x_index: usize, y_index: usize, mut depth: usize) -> bool {
+    loop {
+        let x_character = array.char_at(x_index, depth);
+        let y_character = array.char_at(y_index, depth);
+        if x_character != y_character || x_character.is_none() {
+            return x_character < y_character;
+        }
+        depth += 1;
+    }
+}
+
+fn string_screen<B: Backend>(frame: &mut Frame<B>, words: &[String], title: &str, header: &str, status: &str, narrator: &Narrator<String>, highlight: Option<(usize, Option<usize>)>) {
+    let string_layout = Layout::default()
+                               .direction(Direction::Vertical)
+                               .constraints(
+                                   [
+                                       Constraint::Length(3),
+                                       Constraint::Min(0),
+                                       Constraint::Length(NARRATION_HEIGHT)
+                                   ]
+                                   .as_ref(),
+                               )
+                               .split(frame.size());
+
+    let status_paragraph = Paragraph::new(Span::from(status))
+                                     .style(Style::default()
+                                                  .fg(Color::LightRed))
+                                     .block(Block::default()
+                                                  .title(Span::styled(header, Style::default()
+                                                                                    .fg(Color::Cyan)
+                                                                                    .add_modifier(Modifier::BOLD)))
+                                                  .borders(Borders::ALL)
+                                                  .border_type(BorderType::Rounded)
+                                                  .style(Style::default()
+                                                               .fg(Color::LightGreen)));
+    frame.render_widget(status_paragraph, string_layout[0]);
+    narrator.render(frame, string_layout[2]);
+
+    let visible_rows = string_layout[1].height.saturating_sub(2) as usize;
+    let first_row    = highlight.map_or(0, |(index, _)| index.saturating_sub(visible_rows / 2))
+                                .min(words.len().saturating_sub(visible_rows));
+    let index_width  = words.len().to_string().len();
+
+    let lines: Vec<Spans> = words.iter()
+                                 .enumerate()
+                                 .skip(first_row)
+                                 .take(visible_rows)
+                                 .map(|(index, word)| {
+                                     let mut spans = vec![Span::styled(format!("{:>width$} ", index, width = index_width), Style::default()
+                                                                                                                                .fg(Color::DarkGray))];
+                                     let examined_depth = highlight.filter(|(highlighted_index, _)| *highlighted_index == index)
+                                                                   .map(|(_, depth)| depth);
+                                     let examined_byte  = examined_depth.flatten();
+                                     let word_style     = if examined_depth.is_some() {
+                                         Style::default().fg(Color::Yellow)
+                                     } else {
+                                         Style::default().fg(Color::White)
+                                     };
+
+                                     for (byte_index, character) in word.char_indices() {
+                                         let style = if examined_byte.is_some_and(|depth| (byte_index..byte_index + character.len_utf8()).contains(&depth)) {
+                                             Style::default().fg(Color::Black).bg(Color::LightRed).add_modifier(Modifier::BOLD)
+                                         } else {
+                                             word_style
+                                         };
+                                         spans.push(Span::styled(character.to_string(), style));
+                                     }
+                                     if examined_byte.is_some_and(|depth| depth >= word.len()) {
+                                         spans.push(Span::styled(END_OF_STRING, Style::default().fg(Color::Black).bg(Color::LightRed)));
+                                     }
+
+                                     Spans::from(spans)
+                                 })
+                                 .collect();
+
+    let words_paragraph = Paragraph::new(lines)
+                                    .block(Block::default()
+                                                 .title(Span::styled(title, Style::default()
+                                                                                  .fg(Color::Cyan)
+                                                                                  .add_modifier(Modifier::BOLD)))
+                                                 .title_alignment(Alignment::Center)
+                                                 .borders(Borders::ALL)
+                                                 .border_type(BorderType::Rounded)
+                                                 .style(Style::default()
+                                                              .fg(Color::LightGreen)));
+    frame.render_widget(words_paragraph, string_layout[1]);
+}
+
+fn counters_label(counters: Counters) -> String {
+    format!("Karakterolvasasok: {} | Cserek: {} | Irasok: {}", counters.character_reads, counters.swaps, counters.writes)
+}
+
+pub fn run_string_sort<B: Backend>(terminal: &mut Terminal<B>, algorithm: StringAlgorithm, words: &[String], seed: u64, input_label: &str) -> io::Result<(Counters, u128)> {
+    let title  = format!("{} | {} szo", algorithm.name(), words.len());
+    let header = format!("Vegrehajtasi-ido eredmenyablak | Seed: {} | Bemenet: {}", seed, input_label);
+
+    let mut narrator   = Narrator::new();
+    let animate_all    = words.len() <= MAX_VECTOR_SIZE;
+    let mut last_frame = time::Instant::now();
+
+    let current_time = time::Instant::now();
+
+    let mut array = SortArray::new(words.to_vec(), String::cmp, sort_algorithms::seeded_rng(seed))
+                              .with_observer(|operation, items| {
+                                  narrator.update(operation, items);
+                                  let highlight = match operation {
+                                      Operation::CharRead(index, depth)                   => Some((index, Some(depth))),
+                                      Operation::Swap(index, _) | Operation::Write(index) => Some((index, None)),
+                                      _ => None
+                                  };
+                                  if highlight.is_some() && (animate_all || last_frame.elapsed().as_millis() as u64 >= FRAME_INTERVAL_MILLIS) {
+                                      terminal.draw(|frame| string_screen(frame, items, &title, &header, "", &narrator, highlight)).ok();
+                                      last_frame = time::Instant::now();
+                                  }
+                              });
+    algorithm.sort(&mut array);
+
+    let elapsed_time_millis = current_time.elapsed().as_millis();
+    let counters            = array.counters();
+    let sorted_words        = array.into_items();
+
+    let status = format!("A rendezési algoritmus végrehajtási ideje: {} ms | {} | Narracio: Fel/Le/PgUp/PgDn gorgetes, F szuro, E mentes | Tovabb: <barmely mas billentyu>",
+                         elapsed_time_millis, counters_label(counters));
+    loop {
+        terminal.draw(|frame| string_screen(frame, &sorted_words, &title, &header, &status, &narrator, None))?;
+
+        match event::read() {
+            Ok(Event::Key(key)) => if !narrator.handle_key(key.code, algorithm.name(), algorithm.key(), sorted_words.len(), seed) {
+                break;
+            },
+            Ok(_)  => {}
+            Err(_) => break
+        }
+    }
+
+    Ok((counters, elapsed_time_millis))
+}
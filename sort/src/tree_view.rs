@@ -0,0 +1,209 @@
+use tui::{ backend::Backend,
+           layout::Rect,
+           style::{ Color, Style, Modifier },
+           symbols::Marker,
+           text::Span,
+           widgets::{ Block, Borders, BorderType, canvas::{ Canvas, Line, Points } },
+           Frame };
+use crate::{ search_tree::SearchTree, sort_algorithms::Algorithm, sort_array::Operation, sort_value::SortValue };
+
+const TREE_VIEW_LIMIT: usize = 1023;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TreeKind {
+    Heap,
+    SearchTree
+}
+
+pub struct TreeView<T> {
+    kind: TreeKind,
+    heap_size: usize,
+    path: Vec<usize>,
+    tree: SearchTree<T>,
+    emitted: usize
+}
+
+struct NodePosition {
+    x: f64,
+    y: f64,
+    parent: Option<usize>,
+    label: String
+}
+
+impl<T: SortValue> TreeView<T> {
+    pub fn new(algorithm: Algorithm) -> Option<TreeView<T>> {
+        let kind = match algorithm {
+            Algorithm::HeapSort => TreeKind::Heap,
+            Algorithm::TreeSort => TreeKind::SearchTree,
+            _                   => return None
+        };
+
+        Some(TreeView {
+            kind,
+            heap_size: 0,
+            path: Vec::new(),
+            tree: SearchTree::default(),
+            emitted: 0
+        })
+    }
+
+    pub fn update(&mut self, operation: Operation, items: &[T]) {
+        match operation {
+            Operation::SiftUp(index) => {
+                self.heap_size = index + 1;
+                self.path      = vec![index];
+            }
+            Operation::SiftDown(root, heap_size) => {
+                self.heap_size = heap_size;
+                self.path      = vec![root];
+            }
+            Operation::Swap(i_index, j_index) if self.kind == TreeKind::Heap => {
+                if self.path.last() == Some(&i_index) {
+                    self.path.push(j_index);
+                } else if self.path.last() == Some(&j_index) {
+                    self.path.push(i_index);
+                }
+            }
+            Operation::TreeInsert(index, link) => {
+                let node  = self.tree.attach(items[index].clone(), link);
+                self.path = self.tree.path_to(node);
+            }
+            Operation::Write(position) if self.kind == TreeKind::SearchTree => {
+                self.emitted = position + 1;
+                self.path.clear();
+            }
+            _ => {}
+        }
+    }
+
+    fn heap_positions(&self, items: &[T]) -> Vec<NodePosition> {
+        let heap_size = self.heap_size.min(items.len());
+        let depth     = (usize::BITS - heap_size.leading_zeros()) as f64;
+
+        (0..heap_size).map(|index| {
+                          let level       = (usize::BITS - (index + 1).leading_zeros() - 1) as usize;
+                          let level_start = (1 << level) - 1;
+                          NodePosition {
+                              x: ((index - level_start) as f64 + 0.5) / (1 << level) as f64,
+                              y: depth - level as f64,
+                              parent: index.checked_sub(1).map(|child| child / 2),
+                              label: items[index].label()
+                          }
+                      })
+                      .collect()
+    }
+
+    fn search_tree_positions(&self) -> Vec<NodePosition> {
+        let nodes     = self.tree.nodes();
+        let max_depth = nodes.iter().map(|node| node.depth).max().unwrap_or(0);
+
+        let mut positions: Vec<NodePosition> = nodes.iter()
+                                                    .map(|node| NodePosition {
+                                                        x: 0.0,
+                                                        y: (max_depth + 1 - node.depth) as f64,
+                                                        parent: node.parent,
+                                                        label: node.value.label()
+                                                    })
+                                                    .collect();
+        for (rank, node) in self.tree.in_order().into_iter().enumerate() {
+            positions[node].x = (rank as f64 + 0.5) / nodes.len() as f64;
+        }
+
+        positions
+    }
+
+    pub fn render<B: Backend>(&self, frame: &mut Frame<B>, area: Rect, items: &[T]) {
+        let title = match self.kind {
+            TreeKind::Heap       => format!("Kupac (meret: {}, sarga: szitalasi ut)", self.heap_size.min(items.len())),
+            TreeKind::SearchTree => format!("Binaris keresofa ({} csucs, sarga: beszurasi ut, kek: kiirt)", self.tree.len())
+        };
+        let block = Block::default()
+                          .title(Span::styled(title, Style::default()
+                                                           .fg(Color::Cyan)
+                                                           .add_modifier(Modifier::BOLD)))
+                          .borders(Borders::ALL)
+                          .border_type(BorderType::Rounded)
+                          .style(Style::default()
+                                       .fg(Color::LightGreen));
+
+        let positions = match self.kind {
+            TreeKind::Heap       => self.heap_positions(items),
+            TreeKind::SearchTree => self.search_tree_positions()
+        };
+        if positions.len() > TREE_VIEW_LIMIT {
+            frame.render_widget(block.title(Span::from("A fa tul nagy a megjeleniteshez")), area);
+            return;
+        }
+
+        let inner_area  = block.inner(area);
+        let max_y       = positions.iter().map(|position| position.y).fold(1.0, f64::max);
+        let mut emitted = vec![false; positions.len()];
+        for node in self.tree.in_order().into_iter().take(self.emitted) {
+            emitted[node] = true;
+        }
+        let label_fits = label_fits(&positions, inner_area.width);
+
+        let canvas = Canvas::default()
+                            .block(block)
+                            .marker(Marker::Braille)
+                            .x_bounds([0.0, 1.0])
+                            .y_bounds([0.5, max_y + 0.5])
+                            .paint(|context| {
+                                for (node, position) in positions.iter().enumerate() {
+                                    if let Some(parent) = position.parent {
+                                        let on_path = self.path.contains(&parent) && self.path.contains(&node);
+                                        context.draw(&Line {
+                                            x1: positions[parent].x,
+                                            y1: positions[parent].y,
+                                            x2: position.x,
+                                            y2: position.y,
+                                            color: if on_path { Color::Yellow } else { Color::DarkGray }
+                                        });
+                                    }
+                                }
+                                context.layer();
+
+                                for (node, position) in positions.iter().enumerate() {
+                                    let color = if self.path.contains(&node) {
+                                        Color::Yellow
+                                    } else if emitted[node] {
+                                        Color::LightBlue
+                                    } else {
+                                        Color::White
+                                    };
+
+                                    if label_fits[node] {
+                                        context.print(position.x - position.label.len() as f64 / (2.0 * inner_area.width.max(1) as f64),
+                                                      position.y,
+                                                      Span::styled(position.label.clone(), Style::default()
+                                                                                                 .fg(color)
+                                                                                                 .add_modifier(Modifier::BOLD)));
+                                    } else {
+                                        context.draw(&Points { coords: &[(position.x, position.y)], color });
+                                    }
+                                }
+                            });
+        frame.render_widget(canvas, area);
+    }
+}
+
+fn label_fits(positions: &[NodePosition], width: u16) -> Vec<bool> {
+    let mut order: Vec<usize> = (0..positions.len()).collect();
+    order.sort_by(|x_node, y_node| positions[*x_node].y.total_cmp(&positions[*y_node].y)
+                                                        .then(positions[*x_node].x.total_cmp(&positions[*y_node].x)));
+
+    let mut gaps = vec![1.0f64; positions.len()];
+    for pair in order.windows(2) {
+        let (left, right) = (&positions[pair[0]], &positions[pair[1]]);
+        if left.y == right.y {
+            let gap         = right.x - left.x;
+            gaps[pair[0]]   = gaps[pair[0]].min(gap);
+            gaps[pair[1]]   = gaps[pair[1]].min(gap);
+        }
+    }
+
+    positions.iter()
+             .zip(gaps)
+             .map(|(position, gap)| gap * width as f64 > (position.label.len() + 1) as f64)
+             .collect()
+}
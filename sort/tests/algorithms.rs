@@ -0,0 +1,183 @@
+use rand::Rng;
+use tui_sort_app::{ adversary,
+                    kernels,
+                    linked_sort::ListAlgorithm,
+                    signed_data,
+                    sort_algorithms::{ self, Algorithm },
+                    sort_array::{ Counters, SortArray },
+                    sort_value::SortValue,
+                    string_sort::{ self, StringAlgorithm } };
+
+const SIZES: [usize; 7] = [0, 1, 2, 3, 16, 17, 150];
+const SEEDS: [u64; 3]   = [1, 7, 2024];
+
+const QUICK_SORT_VARIANTS: [Algorithm; 4] = [Algorithm::QuickSort, Algorithm::MedianQuickSort, Algorithm::RandomQuickSort, Algorithm::IntroSort];
+
+type Kernel = fn(&mut [u64]);
+
+fn unsigned_values(size: usize, seed: u64) -> Vec<u64> {
+    let mut rng = sort_algorithms::seeded_rng(seed);
+    (0..size).map(|_| rng.gen_range(0..size.max(1) as u64 / 2 + 1)).collect()
+}
+
+fn sorted<T: SortValue>(values: Vec<T>, algorithm: Algorithm, seed: u64) -> (Vec<T>, Counters) {
+    let mut array = SortArray::new(values, T::compare, sort_algorithms::seeded_rng(seed));
+    algorithm.sort(&mut array);
+    let counters = array.counters();
+    (array.into_items(), counters)
+}
+
+fn expected<T: SortValue>(mut values: Vec<T>) -> Vec<T> {
+    values.sort_by(T::compare);
+    values
+}
+
+fn labels<T: SortValue>(values: &[T]) -> Vec<String> {
+    values.iter().map(SortValue::label).collect()
+}
+
+fn assert_sorts(algorithms: &[Algorithm]) {
+    for algorithm in algorithms {
+        for size in SIZES {
+            for seed in SEEDS {
+                let values = unsigned_values(size, seed);
+                assert_eq!(sorted(values.clone(), *algorithm, seed).0, expected(values), "{} ({} elem, seed {})", algorithm.name(), size, seed);
+            }
+        }
+
+        let ascending: Vec<u64> = (0..100).collect();
+        assert_eq!(sorted(ascending.clone(), *algorithm, 3).0, ascending, "{}", algorithm.name());
+        assert_eq!(sorted(ascending.iter().rev().copied().collect(), *algorithm, 3).0, ascending, "{}", algorithm.name());
+    }
+}
+
+#[test]
+fn quick_sort_variants_sort_values() {
+    assert_sorts(&QUICK_SORT_VARIANTS);
+}
+
+#[test]
+fn every_algorithm_sorts_its_best_and_worst_case_input() {
+    for algorithm in Algorithm::ALL {
+        for size in SIZES {
+            for input in [adversary::best_case_input(algorithm, size, 5), adversary::worst_case_input(algorithm, size, 5)] {
+                assert_eq!(input.len(), size);
+                assert_eq!(sorted(input.clone(), algorithm, 5).0, expected(input), "{} ({} elem)", algorithm.name(), size);
+            }
+        }
+    }
+}
+
+#[test]
+fn worst_case_input_is_quadratic_for_quick_sort_but_not_for_introsort() {
+    let size      = 300;
+    let quadratic = (size * size / 5) as u64;
+    for algorithm in QUICK_SORT_VARIANTS {
+        let (_, counters) = sorted(adversary::worst_case_input(algorithm, size, 5), algorithm, 5);
+        if algorithm == Algorithm::IntroSort {
+            assert!(counters.comparisons < quadratic / 2, "{}: {}", algorithm.name(), counters.comparisons);
+        } else {
+            assert!(counters.comparisons > quadratic, "{}: {}", algorithm.name(), counters.comparisons);
+        }
+    }
+}
+
+#[test]
+fn exchange_sort_family_sorts_values() {
+    assert_sorts(&[Algorithm::CocktailSort, Algorithm::CombSort, Algorithm::OddEvenSort, Algorithm::CycleSort, Algorithm::PancakeSort]);
+}
+
+#[test]
+fn heap_sort_and_tree_sort_sort_values() {
+    assert_sorts(&[Algorithm::HeapSort, Algorithm::TreeSort]);
+}
+
+#[test]
+fn every_string_algorithm_sorts_words() {
+    let mut inputs: Vec<Vec<String>> = SEEDS.iter()
+                                            .map(|seed| string_sort::prepare_words(&[], *seed))
+                                            .collect();
+    inputs.push(Vec::new());
+    inputs.push(["b", "", "ab", "a", "abc", "ab", "b", "aab"].iter()
+                                                             .map(|word| word.to_string())
+                                                             .collect());
+
+    for algorithm in StringAlgorithm::ALL {
+        for words in &inputs {
+            let mut array = SortArray::new(words.clone(), String::cmp, sort_algorithms::seeded_rng(0));
+            algorithm.sort(&mut array);
+
+            let mut expected = words.clone();
+            expected.sort();
+            assert_eq!(array.into_items(), expected, "{}", algorithm.name());
+        }
+    }
+}
+
+#[test]
+fn merge_sort_sorts_values() {
+    assert_sorts(&[Algorithm::MergeSort]);
+}
+
+#[test]
+fn every_algorithm_sorts_signed_values() {
+    for algorithm in Algorithm::ALL {
+        for size in SIZES {
+            for seed in SEEDS {
+                let values = signed_data::generate_signed_values(size, seed);
+                assert_eq!(sorted(values.clone(), algorithm, seed).0, expected(values), "{} ({} elem, seed {})", algorithm.name(), size, seed);
+            }
+        }
+    }
+}
+
+#[test]
+fn every_algorithm_sorts_float_values_in_total_order() {
+    for algorithm in Algorithm::ALL {
+        for size in SIZES {
+            for seed in SEEDS {
+                let mut values = signed_data::generate_float_values(size, seed);
+                values.extend([f64::NAN, -0.0, 0.0, f64::INFINITY, f64::NEG_INFINITY, -f64::NAN]);
+                assert_eq!(labels(&sorted(values.clone(), algorithm, seed).0), labels(&expected(values)), "{} ({} elem, seed {})", algorithm.name(), size, seed);
+            }
+        }
+    }
+
+    let (values, _) = sorted(vec![f64::NAN, 1.5, -0.0, f64::NEG_INFINITY, 0.0, -f64::NAN, -2.0], Algorithm::QuickSort, 0);
+    assert_eq!(labels(&values), ["-NaN", "-inf", "-2", "-0", "0", "1.5", "NaN"]);
+}
+
+#[test]
+fn every_kernel_sorts_values() {
+    let kernels: [(&str, Kernel); 5] = [
+        ("insertion_sort", kernels::insertion_sort),
+        ("branchless_insertion_sort", kernels::branchless_insertion_sort),
+        ("network_sort", kernels::network_sort),
+        ("quick_sort", kernels::quick_sort),
+        ("branchless_quick_sort", kernels::branchless_quick_sort)
+    ];
+
+    for (name, kernel) in kernels {
+        for size in SIZES.into_iter().chain([4, 5, 8, 9, 33, 1000]) {
+            for seed in SEEDS {
+                let mut values = unsigned_values(size, seed);
+                let sorted     = expected(values.clone());
+                kernel(&mut values);
+                assert_eq!(values, sorted, "{} ({} elem, seed {})", name, size, seed);
+            }
+        }
+    }
+}
+
+#[test]
+fn every_list_algorithm_sorts_values() {
+    for algorithm in ListAlgorithm::ALL {
+        for size in SIZES {
+            for seed in SEEDS {
+                let values      = unsigned_values(size, seed);
+                let (result, _) = algorithm.sort(values.clone(), |_, _| {});
+                assert_eq!(result, expected(values), "{} ({} elem, seed {})", algorithm.name(), size, seed);
+            }
+        }
+    }
+}